@@ -0,0 +1,72 @@
+//! Times parse, part 1 and part 2 of every day on its checked-in input.
+//!
+//! Save a baseline before starting performance work, then compare against it:
+//!
+//! ```text
+//! cargo bench -p advent --bench days -- --save-baseline main
+//! cargo bench -p advent --bench days -- --baseline main day7
+//! cargo run -p advent -- bench-check --baseline main --threshold 5
+//! ```
+
+use std::hint::black_box;
+use std::time::Duration;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use utils::input::read_input;
+use utils::Solution;
+
+fn bench_day<S: Solution>(c: &mut Criterion) {
+    let path = advent::runner::solver::<S>().default_input();
+    let text = match read_input(&path) {
+        Ok(text) => text,
+        Err(error) => {
+            eprintln!("skipping day {}: {}", S::DAY, error);
+            return;
+        }
+    };
+    let input = match S::parse(&text) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("skipping day {}: {}", S::DAY, error);
+            return;
+        }
+    };
+
+    let mut group = c.benchmark_group(format!("day{}", S::DAY));
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&text))));
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&input))));
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&input))));
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<day1::Day1>(c);
+    bench_day::<day2::Day2>(c);
+    bench_day::<day3::Day3>(c);
+    bench_day::<day4::Day4>(c);
+    bench_day::<day5::Day5>(c);
+    bench_day::<day6::Day6>(c);
+    bench_day::<day7::Day7>(c);
+    bench_day::<day8::Day8>(c);
+    bench_day::<day9::Day9>(c);
+    bench_day::<day10::Day10>(c);
+    bench_day::<day11::Day11>(c);
+    bench_day::<day12::Day12>(c);
+    bench_day::<day13::Day13>(c);
+    bench_day::<day14::Day14>(c);
+    bench_day::<day15::Day15>(c);
+    bench_day::<day16::Day16>(c);
+    bench_day::<day17::Day17>(c);
+}
+
+criterion_group! {
+    name = benches;
+    // Changes under 5% are reported as noise rather than as a regression.
+    config = Criterion::default()
+        .noise_threshold(0.05)
+        .sample_size(20)
+        .warm_up_time(Duration::from_secs(1))
+        .measurement_time(Duration::from_secs(2));
+    targets = days
+}
+criterion_main!(benches);
@@ -0,0 +1,171 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+/// A benchmark whose last measurement is slower than its saved baseline by
+/// more than the allowed threshold.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    /// Criterion benchmark id, e.g. `day7/part2`.
+    pub id: String,
+    /// Relative change of the mean time, `0.12` meaning 12% slower.
+    pub change: f64,
+}
+
+/// Where `cargo bench` leaves its results for this workspace.
+pub fn default_criterion_dir() -> PathBuf {
+    match std::env::var_os("CARGO_TARGET_DIR") {
+        Some(target) => PathBuf::from(target).join("criterion"),
+        None => Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join("target")
+            .join("criterion"),
+    }
+}
+
+/// Compares the last measurement of every benchmark, criterion's `new`
+/// estimates, with the ones saved by `--save-baseline <baseline>`. A
+/// benchmark is flagged when even the lower bound of its mean is slower
+/// than the upper bound of the baseline mean by more than `threshold` (a
+/// fraction, `0.05` for 5%), so that noisy measurements are not reported.
+/// Fails when no benchmark has that baseline.
+pub fn regressions(
+    criterion_dir: &Path,
+    baseline: &str,
+    threshold: f64,
+) -> io::Result<Vec<Regression>> {
+    let mut compared = 0;
+    let mut result = Vec::new();
+    collect(
+        criterion_dir,
+        criterion_dir,
+        baseline,
+        threshold,
+        &mut compared,
+        &mut result,
+    )?;
+    if compared == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("no benchmark has a baseline named {:?}", baseline),
+        ));
+    }
+    result.sort_by(|a, b| a.id.cmp(&b.id));
+    Ok(result)
+}
+
+/// The point estimate and confidence interval of the mean time in a
+/// criterion `estimates.json`.
+fn mean(path: &Path) -> io::Result<Option<(f64, f64, f64)>> {
+    let json: Value = serde_json::from_str(&fs::read_to_string(path)?)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let mean = &json["mean"];
+    let interval = &mean["confidence_interval"];
+    Ok(mean["point_estimate"]
+        .as_f64()
+        .zip(interval["lower_bound"].as_f64())
+        .zip(interval["upper_bound"].as_f64())
+        .map(|((point, lower), upper)| (point, lower, upper)))
+}
+
+fn collect(
+    root: &Path,
+    dir: &Path,
+    baseline: &str,
+    threshold: f64,
+    compared: &mut usize,
+    result: &mut Vec<Regression>,
+) -> io::Result<()> {
+    // A benchmark directory holds its estimates, one directory per
+    // baseline; nested benchmarks only appear under groups.
+    let latest = dir.join("new").join("estimates.json");
+    if latest.is_file() {
+        let saved = dir.join(baseline).join("estimates.json");
+        if !saved.is_file() {
+            return Ok(());
+        }
+        *compared += 1;
+
+        if let (Some((point, lower, _)), Some((base_point, _, base_upper))) =
+            (mean(&latest)?, mean(&saved)?)
+        {
+            if lower / base_upper - 1.0 > threshold {
+                let id = dir.strip_prefix(root).unwrap_or(dir);
+                result.push(Regression {
+                    id: id.to_string_lossy().replace('\\', "/"),
+                    change: point / base_point - 1.0,
+                });
+            }
+        }
+        return Ok(());
+    }
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() && path.file_name().is_some_and(|name| name != "report") {
+            collect(root, &path, baseline, threshold, compared, result)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Writes the estimates of benchmark `id` in directory `name`, with a
+    /// mean of `point` nanoseconds give or take `margin`.
+    fn write_estimates(root: &Path, id: &str, name: &str, point: f64, margin: f64) {
+        let dir = root.join(id).join(name);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("estimates.json"),
+            format!(
+                r#"{{"mean":{{"confidence_interval":{{"confidence_level":0.95,"lower_bound":{},"upper_bound":{}}},"point_estimate":{},"standard_error":1.0}}}}"#,
+                point - margin,
+                point + margin,
+                point
+            ),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_regressions() {
+        let root = std::env::temp_dir().join(format!("advent-bench-{}", std::process::id()));
+        for (id, main, new) in [
+            ("day7/part2", 100.0, 130.0),
+            ("day7/part1", 100.0, 101.0),
+            ("day9/part2", 100.0, 108.0),
+            ("day17/parse", 100.0, 60.0),
+        ] {
+            write_estimates(&root, id, "main", main, 1.0);
+            write_estimates(&root, id, "new", new, 1.0);
+            // The last comparison criterion made, against another baseline.
+            write_estimates(&root, id, "change", 0.0, 0.0);
+        }
+        // Measured, but not part of the baseline.
+        write_estimates(&root, "day8/part1", "new", 500.0, 1.0);
+        fs::create_dir_all(root.join("report")).unwrap();
+
+        let found = regressions(&root, "main", 0.05).unwrap();
+        assert_eq!(
+            vec!["day7/part2", "day9/part2"],
+            found.iter().map(|r| r.id.as_str()).collect::<Vec<_>>()
+        );
+        assert!((found[0].change - 0.30).abs() < 1e-9);
+        assert_eq!(1, regressions(&root, "main", 0.1).unwrap().len());
+
+        assert!(regressions(&root, "base", 0.05).is_err());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_missing_dir() {
+        assert!(regressions(Path::new("./no-criterion-here"), "main", 0.05).is_err());
+    }
+}
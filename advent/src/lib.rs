@@ -0,0 +1,10 @@
+//! Runs every day of the calendar behind a single `advent` command.
+
+pub mod bench;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+
+pub use registry::{days, find};
+pub use runner::{DayReport, PartReport, Solver};
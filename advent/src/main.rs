@@ -0,0 +1,369 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+use advent::bench;
+use advent::report::{json_lines, table, DayOutcome};
+use advent::runner::input_path;
+use advent::scaffold;
+use advent::Solver;
+use utils::fetch::{self, Client, FetchError};
+use utils::input::read_source;
+use utils::submit::{self, Hint, History, Outcome, Submission, Verdict};
+use utils::Answer;
+
+#[derive(Parser)]
+#[command(name = "advent", about = "Advent of Code 2021 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or every day with --all
+    Run(RunArgs),
+    /// Create and register the crate of a new day
+    New(NewArgs),
+    /// Download puzzle inputs into each day's input.txt
+    Fetch(FetchArgs),
+    /// Solve one part and send its answer, unless it is already known wrong
+    Submit(SubmitArgs),
+    /// Fail if the last `cargo bench` is slower than a saved baseline
+    BenchCheck(BenchCheckArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to solve
+    #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+
+    /// Only solve this part (1 or 2)
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Puzzle input, `-` for stdin; defaults to the day's input.txt
+    #[arg(long, conflicts_with = "all")]
+    input: Option<PathBuf>,
+
+    /// Solve every day with its own input
+    #[arg(long)]
+    all: bool,
+
+    /// How to print the results
+    #[arg(long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// Aligned columns, one row per part
+    Table,
+    /// One JSON object per part and line
+    Json,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Day to create
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Day to download
+    #[arg(long, required_unless_present = "all", conflicts_with = "all", value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Download the input of every solved day
+    #[arg(long)]
+    all: bool,
+
+    /// Replace an existing input.txt
+    #[arg(long)]
+    force: bool,
+
+    /// Server to download from, defaults to $AOC_BASE_URL or adventofcode.com
+    #[arg(long)]
+    base_url: Option<String>,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// Day to submit
+    #[arg(long)]
+    day: u8,
+
+    /// Part to submit (1 or 2)
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Puzzle input, `-` for stdin; defaults to the day's input.txt
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// Answer to send instead of the computed one, e.g. the letters read
+    /// from a drawing
+    #[arg(long)]
+    answer: Option<String>,
+
+    /// Server to submit to, defaults to $AOC_BASE_URL or adventofcode.com
+    #[arg(long)]
+    base_url: Option<String>,
+}
+
+#[derive(Args)]
+struct BenchCheckArgs {
+    /// Baseline saved with `cargo bench -- --save-baseline <name>`
+    #[arg(long)]
+    baseline: String,
+
+    /// Largest accepted slowdown, in percent
+    #[arg(long, default_value_t = 5.0)]
+    threshold: f64,
+
+    /// Criterion output directory, defaults to target/criterion
+    #[arg(long)]
+    dir: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run(args) => run(args),
+        Command::New(args) => new(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Submit(args) => submit(args),
+        Command::BenchCheck(args) => bench_check(args),
+    }
+}
+
+fn run(args: RunArgs) -> ExitCode {
+    let parts: Vec<u8> = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let solvers: Vec<Solver> = match args.day {
+        Some(day) => match advent::find(day) {
+            Some(solver) => vec![solver],
+            None => {
+                eprintln!("day {} is not solved yet", day);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => advent::days(),
+    };
+
+    let outcomes: Vec<DayOutcome> = solvers
+        .iter()
+        .map(|solver| {
+            let path = args.input.clone().unwrap_or_else(|| solver.default_input());
+            let outcome = read_source(path).and_then(|text| solver.run(&text, &parts));
+            (solver.day, outcome)
+        })
+        .collect();
+
+    match args.format {
+        Format::Table => print!("{}", table(&outcomes)),
+        Format::Json => print!("{}", json_lines(&outcomes)),
+    }
+
+    let failed = outcomes.iter().any(|(_, outcome)| match outcome {
+        Ok(report) => report.parts.iter().any(|part| part.answer.is_err()),
+        Err(_) => true,
+    });
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn new(args: NewArgs) -> ExitCode {
+    let root = scaffold::workspace_root();
+    match scaffold::new_day(&root, args.day) {
+        Ok(written) => {
+            for path in written {
+                let path = path.strip_prefix(&root).unwrap_or(&path);
+                println!("wrote {}", path.display());
+            }
+            println!(
+                "fill day{}/input.txt with `advent fetch --day {}`",
+                args.day, args.day
+            );
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("day {}: {}", args.day, error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// A client for `base_url`, or for the server given by the environment.
+fn client(base_url: Option<String>) -> Result<Client, FetchError> {
+    match base_url {
+        Some(base_url) => fetch::find_session(fetch::session_config_path().as_deref())
+            .map(|session| Client::new(&base_url, &session, fetch::default_cache_dir())),
+        None => Client::from_env(),
+    }
+}
+
+fn fetch(args: FetchArgs) -> ExitCode {
+    let client = match client(args.base_url) {
+        Ok(client) => client,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let days: Vec<u8> = match args.day {
+        Some(day) => vec![day],
+        None => advent::days().iter().map(|solver| solver.day).collect(),
+    };
+
+    let mut status = ExitCode::SUCCESS;
+    for day in days {
+        let path = input_path(day);
+        // `advent new` leaves an empty placeholder to be filled here.
+        let placeholder = fs::metadata(&path).is_ok_and(|meta| meta.len() == 0);
+        if path.exists() && !placeholder && !args.force {
+            println!("day {}: {} already exists", day, path.display());
+            continue;
+        }
+        if !path.parent().is_some_and(|dir| dir.is_dir()) {
+            eprintln!("day {}: no day{} crate to hold its input", day, day);
+            status = ExitCode::FAILURE;
+            continue;
+        }
+
+        match client.input(day).and_then(|text| {
+            fs::write(&path, text).map_err(|source| FetchError::Io {
+                path: path.clone(),
+                source,
+            })
+        }) {
+            Ok(()) => println!("day {}: wrote {}", day, path.display()),
+            Err(error) => {
+                eprintln!("day {}: {}", day, error);
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+    status
+}
+
+fn submit(args: SubmitArgs) -> ExitCode {
+    let answer = match args.answer {
+        Some(answer) => answer,
+        None => match compute(args.day, args.part, args.input) {
+            Ok(answer) => answer,
+            Err(message) => {
+                eprintln!("day {} part {}: {}", args.day, args.part, message);
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+
+    let sent = client(args.base_url).and_then(|client| {
+        let mut history = History::for_client(&client)?;
+        submit::submit(&client, &mut history, args.day, args.part, &answer)
+    });
+    let verdict = match sent {
+        Ok(Submission::Known(entry)) => {
+            let reason = match entry.outcome {
+                Outcome::Right => format!("already solved with {}", entry.answer),
+                Outcome::Wrong(Some(Hint::TooHigh)) => format!("{} was too high", entry.answer),
+                Outcome::Wrong(Some(Hint::TooLow)) => format!("{} was too low", entry.answer),
+                Outcome::Wrong(None) => "already rejected".to_string(),
+            };
+            println!("{}: not sent, {}", answer, reason);
+            return if entry.outcome == Outcome::Right && entry.answer == answer {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            };
+        }
+        Ok(Submission::Sent(verdict)) => verdict,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let wait = |wait: Option<std::time::Duration>| match wait {
+        Some(wait) => format!(", wait {}s before the next try", wait.as_secs()),
+        None => String::new(),
+    };
+    let solved = matches!(verdict, Verdict::Right | Verdict::AlreadySolved);
+    match verdict {
+        Verdict::Right => println!("{}: right answer", answer),
+        Verdict::Wrong { hint, wait: delay } => {
+            let hint = match hint {
+                Some(Hint::TooHigh) => " (too high)",
+                Some(Hint::TooLow) => " (too low)",
+                None => "",
+            };
+            println!("{}: wrong answer{}{}", answer, hint, wait(delay));
+        }
+        Verdict::TooSoon { wait: delay } => println!("{}: not checked{}", answer, wait(delay)),
+        Verdict::AlreadySolved => println!("{}: part already solved or locked", answer),
+        Verdict::Unknown(text) => println!("{}: unexpected reply: {}", answer, text),
+    }
+
+    if solved {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Solves one part; drawn answers cannot be sent as is and must be read and
+/// passed with --answer.
+fn compute(day: u8, part: u8, input: Option<PathBuf>) -> Result<String, String> {
+    let solver = advent::find(day).ok_or_else(|| "not solved yet".to_string())?;
+    let path = input.unwrap_or_else(|| solver.default_input());
+    let report = read_source(path)
+        .and_then(|text| solver.run(&text, &[part]))
+        .map_err(|error| error.to_string())?;
+
+    match report.parts.into_iter().next().map(|part| part.answer) {
+        Some(Ok(Answer::Int(value))) => Ok(value.to_string()),
+        Some(Ok(Answer::Text(_))) => {
+            Err("the answer is a drawing, pass it with --answer".to_string())
+        }
+        Some(Err(error)) => Err(error.to_string()),
+        None => Err("no answer".to_string()),
+    }
+}
+
+fn bench_check(args: BenchCheckArgs) -> ExitCode {
+    let dir = args.dir.unwrap_or_else(bench::default_criterion_dir);
+
+    match bench::regressions(&dir, &args.baseline, args.threshold / 100.0) {
+        Ok(regressions) if regressions.is_empty() => {
+            println!(
+                "no regression above {}% against {}",
+                args.threshold, args.baseline
+            );
+            ExitCode::SUCCESS
+        }
+        Ok(regressions) => {
+            for regression in regressions {
+                println!("{:<16} {:+.1}%", regression.id, regression.change * 100.0);
+            }
+            ExitCode::FAILURE
+        }
+        Err(error) => {
+            eprintln!("cannot read {}: {}", dir.display(), error);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use crate::runner::{solver, Solver};
+
+/// The day of each solver in [`days`], in the same order.
+pub const DAYS: &[u8] = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17];
+
+/// Every solved day, in calendar order.
+pub fn days() -> Vec<Solver> {
+    vec![
+        solver::<day1::Day1>(),
+        solver::<day2::Day2>(),
+        solver::<day3::Day3>(),
+        solver::<day4::Day4>(),
+        solver::<day5::Day5>(),
+        solver::<day6::Day6>(),
+        solver::<day7::Day7>(),
+        solver::<day8::Day8>(),
+        solver::<day9::Day9>(),
+        solver::<day10::Day10>(),
+        solver::<day11::Day11>(),
+        solver::<day12::Day12>(),
+        solver::<day13::Day13>(),
+        solver::<day14::Day14>(),
+        solver::<day15::Day15>(),
+        solver::<day16::Day16>(),
+        solver::<day17::Day17>(),
+    ]
+}
+
+pub fn find(day: u8) -> Option<Solver> {
+    days().into_iter().find(|solver| solver.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_are_in_order() {
+        let days: Vec<u8> = days().iter().map(|solver| solver.day).collect();
+        assert_eq!(DAYS, days);
+        assert!(DAYS.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(Some(14), find(14).map(|solver| solver.day));
+        assert!(find(25).is_none());
+    }
+}
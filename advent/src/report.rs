@@ -0,0 +1,243 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use serde_json::{json, Number, Value};
+use utils::{Answer, InputError};
+
+use crate::runner::DayReport;
+
+/// Outcome of running one day: either its report, or why its input could not
+/// be read or parsed.
+pub type DayOutcome = (u8, Result<DayReport, InputError>);
+
+/// Renders outcomes as a table with one row per part. Multi-line answers
+/// continue on the following rows, under the answer column.
+pub fn table(outcomes: &[DayOutcome]) -> String {
+    let mut rows: Vec<[String; 5]> = Vec::new();
+
+    for (day, outcome) in outcomes {
+        match outcome {
+            Ok(report) => {
+                for (index, part) in report.parts.iter().enumerate() {
+                    let parse = if index == 0 {
+                        format_duration(report.parse_time)
+                    } else {
+                        String::new()
+                    };
+                    let answer = match &part.answer {
+                        Ok(answer) => answer.to_string(),
+                        Err(error) => format!("error: {}", error),
+                    };
+                    let mut lines = answer.lines();
+                    rows.push([
+                        day.to_string(),
+                        parse,
+                        part.part.to_string(),
+                        lines.next().unwrap_or_default().to_string(),
+                        format_duration(part.elapsed),
+                    ]);
+                    for line in lines {
+                        rows.push([
+                            String::new(),
+                            String::new(),
+                            String::new(),
+                            line.to_string(),
+                            String::new(),
+                        ]);
+                    }
+                }
+            }
+            Err(error) => rows.push([
+                day.to_string(),
+                String::new(),
+                String::new(),
+                format!("error: {}", error),
+                String::new(),
+            ]),
+        }
+    }
+
+    let header = ["Day", "Parse", "Part", "Answer", "Time"];
+    let mut widths = header.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut output = String::new();
+    for row in std::iter::once(header.map(String::from)).chain(rows) {
+        let _ = writeln!(
+            output,
+            "{:>w0$}  {:>w1$}  {:>w2$}  {:<w3$}  {:>w4$}",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            row[4],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+            w4 = widths[4],
+        );
+    }
+    output
+        .lines()
+        .map(str::trim_end)
+        .fold(String::new(), |acc, line| acc + line + "\n")
+}
+
+/// Renders outcomes as JSON lines, one object per part:
+/// `{"day":13,"part":1,"answer":17,"elapsed_ns":5000,"parse_ns":3000}`.
+/// Drawn answers are strings, and a part that failed has an `error` instead
+/// of an `answer`. A day whose input could not be parsed has a single line
+/// without `part`.
+pub fn json_lines(outcomes: &[DayOutcome]) -> String {
+    let mut output = String::new();
+
+    for (day, outcome) in outcomes {
+        match outcome {
+            Ok(report) => {
+                for part in &report.parts {
+                    let mut line = json!({
+                        "day": day,
+                        "part": part.part,
+                        "elapsed_ns": part.elapsed.as_nanos() as u64,
+                        "parse_ns": report.parse_time.as_nanos() as u64,
+                    });
+                    match &part.answer {
+                        Ok(answer) => line["answer"] = answer_value(answer),
+                        Err(error) => line["error"] = Value::from(error.to_string()),
+                    }
+                    let _ = writeln!(output, "{}", line);
+                }
+            }
+            Err(error) => {
+                let line = json!({ "day": day, "error": error.to_string() });
+                let _ = writeln!(output, "{}", line);
+            }
+        }
+    }
+    output
+}
+
+/// Integers stay numbers unless they do not fit in 64 bits.
+fn answer_value(answer: &Answer) -> Value {
+    match answer {
+        Answer::Int(value) => Number::from_i128(*value)
+            .map(Value::Number)
+            .unwrap_or_else(|| Value::from(value.to_string())),
+        Answer::Text(_) => Value::from(answer.to_string()),
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_secs_f64() * 1_000_000.0;
+    if micros < 1_000.0 {
+        format!("{:.1} µs", micros)
+    } else if micros < 1_000_000.0 {
+        format!("{:.1} ms", micros / 1_000.0)
+    } else {
+        format!("{:.2} s", micros / 1_000_000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::PartReport;
+    use utils::SolveError;
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!("12.0 µs", format_duration(Duration::from_micros(12)));
+        assert_eq!("1.5 ms", format_duration(Duration::from_micros(1_500)));
+        assert_eq!("2.00 s", format_duration(Duration::from_secs(2)));
+    }
+
+    fn sample() -> Vec<DayOutcome> {
+        vec![
+            (
+                13,
+                Ok(DayReport {
+                    day: 13,
+                    parse_time: Duration::from_micros(3),
+                    parts: vec![
+                        PartReport {
+                            part: 1,
+                            answer: Ok(Answer::Int(17)),
+                            elapsed: Duration::from_micros(5),
+                        },
+                        PartReport {
+                            part: 2,
+                            answer: Ok(Answer::Text("#.#\n.#.\n".to_string())),
+                            elapsed: Duration::from_micros(7),
+                        },
+                    ],
+                }),
+            ),
+            (
+                14,
+                Ok(DayReport {
+                    day: 14,
+                    parse_time: Duration::from_micros(1),
+                    parts: vec![PartReport {
+                        part: 1,
+                        answer: Err(SolveError::new("boom")),
+                        elapsed: Duration::from_micros(1),
+                    }],
+                }),
+            ),
+            (15, Err(InputError::parse(1, 2, "bad digit"))),
+        ]
+    }
+
+    #[test]
+    fn test_table() {
+        let outcomes = sample();
+        let expected = "\
+Day   Parse  Part  Answer                                Time
+ 13  3.0 µs     1  17                                  5.0 µs
+ 13             2  #.#                                 7.0 µs
+                   .#.
+ 14  1.0 µs     1  error: boom                         1.0 µs
+ 15                error: line 1, column 2: bad digit
+";
+        assert_eq!(expected, table(&outcomes));
+    }
+
+    #[test]
+    fn test_json_lines() {
+        let output = json_lines(&sample());
+        let lines: Vec<Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(4, lines.len());
+        assert_eq!(
+            json!({"day": 13, "part": 1, "answer": 17, "elapsed_ns": 5000, "parse_ns": 3000}),
+            lines[0]
+        );
+        assert_eq!(json!("#.#\n.#."), lines[1]["answer"]);
+        assert_eq!(json!("boom"), lines[2]["error"]);
+        assert!(lines[2].get("answer").is_none());
+        assert_eq!(
+            json!({"day": 15, "error": "line 1, column 2: bad digit"}),
+            lines[3]
+        );
+    }
+
+    #[test]
+    fn test_large_answer() {
+        assert_eq!(
+            json!(4358595186090_i64),
+            answer_value(&Answer::Int(4358595186090))
+        );
+        assert_eq!(
+            json!(i128::MAX.to_string()),
+            answer_value(&Answer::Int(i128::MAX))
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use utils::{Answer, InputError, Solution, SolveError};
+
+/// Type-erased handle on a day's [`Solution`], so that days with different
+/// input models can live in the same registry.
+#[derive(Clone, Copy)]
+pub struct Solver {
+    pub day: u8,
+    run: fn(&str, &[u8]) -> Result<DayReport, InputError>,
+}
+
+pub fn solver<S: Solution>() -> Solver {
+    Solver {
+        day: S::DAY,
+        run: run::<S>,
+    }
+}
+
+#[derive(Debug)]
+pub struct DayReport {
+    pub day: u8,
+    pub parse_time: Duration,
+    pub parts: Vec<PartReport>,
+}
+
+#[derive(Debug)]
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Result<Answer, SolveError>,
+    pub elapsed: Duration,
+}
+
+impl Solver {
+    /// Parses `text` once, then answers the requested parts (1 and/or 2).
+    pub fn run(&self, text: &str, parts: &[u8]) -> Result<DayReport, InputError> {
+        (self.run)(text, parts)
+    }
+
+    /// The puzzle input checked into the day's crate.
+    pub fn default_input(&self) -> PathBuf {
+        input_path(self.day)
+    }
+}
+
+/// `dayN/input.txt` in the workspace, whether or not the day is solved yet.
+pub fn input_path(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{}", day))
+        .join("input.txt")
+}
+
+fn run<S: Solution>(text: &str, parts: &[u8]) -> Result<DayReport, InputError> {
+    let start = Instant::now();
+    let input = S::parse(text)?;
+    let parse_time = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => S::part1(&input),
+                _ => S::part2(&input),
+            };
+            PartReport {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(DayReport {
+        day: S::DAY,
+        parse_time,
+        parts,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_single_part() {
+        let report = solver::<day7::Day7>()
+            .run("16,1,2,0,4,2,7,1,2,14", &[2])
+            .unwrap();
+
+        assert_eq!(7, report.day);
+        assert_eq!(1, report.parts.len());
+        assert_eq!(2, report.parts[0].part);
+        assert_eq!(Ok(Answer::Int(168)), report.parts[0].answer);
+    }
+
+    #[test]
+    fn test_run_parse_error() {
+        assert!(solver::<day7::Day7>().run("16,x", &[1, 2]).is_err());
+    }
+}
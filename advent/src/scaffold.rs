@@ -0,0 +1,502 @@
+//! Creates the crate of a new day and registers it everywhere a day is
+//! listed: the workspace members, the `advent` dependencies, the registry,
+//! the benches and the fuzz targets.
+
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// `dayN` already exists; nothing was written.
+    Exists(PathBuf),
+    /// A file listing the days does not have the expected shape.
+    Layout {
+        path: PathBuf,
+        message: String,
+    },
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::Exists(path) => write!(f, "{} already exists", path.display()),
+            ScaffoldError::Layout { path, message } => {
+                write!(f, "{}: {}", path.display(), message)
+            }
+            ScaffoldError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+        }
+    }
+}
+
+impl Error for ScaffoldError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ScaffoldError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// The workspace this binary was built from.
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+/// Creates `dayN` under `root` and registers it. Returns every file written,
+/// created ones first.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let name = format!("day{}", day);
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(ScaffoldError::Exists(dir));
+    }
+
+    // Every edit is prepared before anything is written, so that a
+    // workspace that does not look as expected is left untouched.
+    let edits = [
+        (root.join("Cargo.toml"), add_member as Edit),
+        (root.join("advent").join("Cargo.toml"), add_dependency),
+        (
+            root.join("advent").join("src").join("registry.rs"),
+            add_solver,
+        ),
+        (
+            root.join("advent").join("benches").join("days.rs"),
+            add_bench,
+        ),
+        (root.join("fuzz").join("Cargo.toml"), add_fuzz_target),
+    ];
+    let mut updated = Vec::new();
+    for (path, edit) in edits {
+        let text = read(&path)?;
+        let text = edit(&text, day).map_err(|message| ScaffoldError::Layout {
+            path: path.clone(),
+            message,
+        })?;
+        updated.push((path, text));
+    }
+
+    let created = [
+        (dir.join("Cargo.toml"), manifest(day)),
+        (dir.join("input.txt"), String::new()),
+        (dir.join("src").join("main.rs"), main_rs(day)),
+        (dir.join("src").join("lib.rs"), lib_rs(day)),
+        (
+            root.join("fuzz")
+                .join("fuzz_targets")
+                .join(format!("{}.rs", name)),
+            fuzz_rs(day),
+        ),
+    ];
+    write(&dir.join("src"), None)?;
+
+    let mut written = Vec::new();
+    for (path, text) in created.into_iter().chain(updated) {
+        write(&path, Some(&text))?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+type Edit = fn(&str, u8) -> Result<String, String>;
+
+/// Appends `"dayN",` to the `members` list of the workspace manifest.
+fn add_member(manifest: &str, day: u8) -> Result<String, String> {
+    let start = manifest
+        .find("members = [")
+        .ok_or("no `members = [` list")?;
+    let end = start
+        + manifest[start..]
+            .find(']')
+            .ok_or("unterminated `members` list")?;
+
+    let entry = format!("\"day{}\"", day);
+    if manifest[start..end].contains(&entry) {
+        return Err(format!("{} is already a member", entry));
+    }
+    Ok(format!(
+        "{}    {},\n{}",
+        &manifest[..end],
+        entry,
+        &manifest[end..]
+    ))
+}
+
+/// Adds `dayN = {path = "../dayN"}` after the last day dependency.
+fn add_dependency(manifest: &str, day: u8) -> Result<String, String> {
+    insert_after_last(
+        manifest,
+        |line| line.starts_with("day") && line.contains("{path"),
+        &format!("day{} = {{path = \"../day{}\"}}", day, day),
+    )
+    .ok_or_else(|| "no day dependency to add to".to_string())
+}
+
+/// Adds `solver::<dayN::DayN>(),` after the last solver of the registry, and
+/// `N` at the end of `DAYS`.
+fn add_solver(registry: &str, day: u8) -> Result<String, String> {
+    let registry = insert_after_last(
+        registry,
+        |line| line.trim_start().starts_with("solver::<day"),
+        &format!("        solver::<day{}::Day{}>(),", day, day),
+    )
+    .ok_or("no solver list to add to")?;
+
+    let start = registry.find(DAYS_START).ok_or("no `DAYS` list")? + DAYS_START.len();
+    let end = start
+        + registry[start..]
+            .find("];")
+            .ok_or("unterminated `DAYS` list")?;
+    let mut days = registry[start..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(|item| item.parse::<u8>())
+        .collect::<Result<Vec<u8>, _>>()
+        .map_err(|_| "`DAYS` is not a list of numbers")?;
+    if days.contains(&day) {
+        return Err(format!("day {} is already in `DAYS`", day));
+    }
+    days.push(day);
+
+    Ok(format!(
+        "{}{}{}",
+        &registry[..start],
+        days_list(&days),
+        &registry[end..]
+    ))
+}
+
+const DAYS_START: &str = "pub const DAYS: &[u8] = &[";
+
+/// The items of `DAYS` laid out as rustfmt does: on the same line while
+/// they fit in 60 columns, else wrapped at 100 columns.
+fn days_list(days: &[u8]) -> String {
+    let items: Vec<String> = days.iter().map(u8::to_string).collect();
+    let single = items.join(", ");
+    if single.len() <= 60 {
+        return single;
+    }
+
+    let mut output = String::from("\n");
+    let mut line = String::new();
+    for item in items {
+        if !line.is_empty() && 4 + line.len() + 2 + item.len() + 1 > 100 {
+            output.push_str(&format!("    {},\n", line));
+            line.clear();
+        }
+        if !line.is_empty() {
+            line.push_str(", ");
+        }
+        line.push_str(&item);
+    }
+    output.push_str(&format!("    {},\n", line));
+    output
+}
+
+/// Adds `bench_day::<dayN::DayN>(c);` after the last bench.
+fn add_bench(benches: &str, day: u8) -> Result<String, String> {
+    insert_after_last(
+        benches,
+        |line| line.trim_start().starts_with("bench_day::<day"),
+        &format!("    bench_day::<day{}::Day{}>(c);", day, day),
+    )
+    .ok_or_else(|| "no bench list to add to".to_string())
+}
+
+/// Adds the day as a dependency of the fuzz crate, and a `[[bin]]` for its
+/// target at the end of the manifest.
+fn add_fuzz_target(manifest: &str, day: u8) -> Result<String, String> {
+    let name = format!("name = \"day{}\"", day);
+    if !manifest.contains("[[bin]]") {
+        return Err("no fuzz target to add to".to_string());
+    }
+    if manifest.contains(&name) {
+        return Err(format!("day{} already has a fuzz target", day));
+    }
+
+    let mut manifest = add_dependency(manifest, day)?;
+    if !manifest.ends_with('\n') {
+        manifest.push('\n');
+    }
+    manifest.push_str(&format!(
+        "\n[[bin]]\n{}\npath = \"fuzz_targets/day{}.rs\"\ntest = false\ndoc = false\nbench = false\n",
+        name, day
+    ));
+    Ok(manifest)
+}
+
+fn insert_after_last(
+    text: &str,
+    is_entry: impl Fn(&str) -> bool,
+    new_line: &str,
+) -> Option<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let last = lines.iter().rposition(|line| is_entry(line))?;
+
+    let mut output = String::with_capacity(text.len() + new_line.len() + 1);
+    for (index, line) in lines.iter().enumerate() {
+        output.push_str(line);
+        output.push('\n');
+        if index == last {
+            output.push_str(new_line);
+            output.push('\n');
+        }
+    }
+    Some(output)
+}
+
+fn manifest(day: u8) -> String {
+    format!(
+        r#"[package]
+name = "day{day}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+utils = {{path = "../utils"}}
+"#
+    )
+}
+
+fn main_rs(day: u8) -> String {
+    format!(
+        r#"fn main() {{
+    utils::solution::run_main::<day{day}::Day{day}>(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+}}
+"#
+    )
+}
+
+fn lib_rs(day: u8) -> String {
+    format!(
+        r#"use utils::{{Answer, InputError, Solution, SolveError}};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    const DAY: u8 = {day};
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {{
+        Ok(input.lines().map(str::to_string).collect())
+    }}
+
+    fn part1(_input: &Self::Input) -> Result<Answer, SolveError> {{
+        Err(SolveError::new("part 1 is not solved yet"))
+    }}
+
+    fn part2(_input: &Self::Input) -> Result<Answer, SolveError> {{
+        Err(SolveError::new("part 2 is not solved yet"))
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    /// The example of the puzzle text.
+    const EXAMPLE: &str = "\
+paste the example here
+";
+
+    #[test]
+    fn test_example() {{
+        let input = Day{day}::parse(EXAMPLE).unwrap();
+        assert!(!input.is_empty());
+    }}
+}}
+"#
+    )
+}
+
+fn fuzz_rs(day: u8) -> String {
+    format!(
+        r#"#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use utils::Solution;
+
+fuzz_target!(|data: &[u8]| {{
+    if let Ok(text) = std::str::from_utf8(data) {{
+        let _ = day{day}::Day{day}::parse(text);
+    }}
+}});
+"#
+    )
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|source| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Writes `text` to `path`, or creates the directory `path` when there is no
+/// text.
+fn write(path: &Path, text: Option<&str>) -> Result<(), ScaffoldError> {
+    let result = match text {
+        Some(text) => fs::write(path, text),
+        None => fs::create_dir_all(path),
+    };
+    result.map_err(|source| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORKSPACE: &str = "[workspace]\n\nmembers = [\n    \"utils\",\n    \"day1\",\n]\n";
+    const ADVENT: &str = "[dependencies]\nclap = \"4\"\nday1 = {path = \"../day1\"}\n\n[dev-dependencies]\ntoml = \"0.8\"\n";
+    const REGISTRY: &str = "pub const DAYS: &[u8] = &[1];\n\npub fn days() -> Vec<Solver> {\n    vec![\n        solver::<day1::Day1>(),\n    ]\n}\n";
+    const BENCHES: &str = "fn days(c: &mut Criterion) {\n    bench_day::<day1::Day1>(c);\n}\n";
+    const FUZZ: &str = "[dependencies]\nday1 = {path = \"../day1\"}\n\n[[bin]]\nname = \"day1\"\npath = \"fuzz_targets/day1.rs\"\n";
+
+    fn workspace(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("advent-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("advent").join("src")).unwrap();
+        fs::create_dir_all(root.join("advent").join("benches")).unwrap();
+        fs::create_dir_all(root.join("fuzz").join("fuzz_targets")).unwrap();
+        fs::write(root.join("Cargo.toml"), WORKSPACE).unwrap();
+        fs::write(root.join("advent").join("benches").join("days.rs"), BENCHES).unwrap();
+        fs::write(root.join("fuzz").join("Cargo.toml"), FUZZ).unwrap();
+        fs::write(root.join("advent").join("Cargo.toml"), ADVENT).unwrap();
+        fs::write(
+            root.join("advent").join("src").join("registry.rs"),
+            REGISTRY,
+        )
+        .unwrap();
+        root
+    }
+
+    #[test]
+    fn test_edits() {
+        assert_eq!(
+            "[workspace]\n\nmembers = [\n    \"utils\",\n    \"day1\",\n    \"day2\",\n]\n",
+            add_member(WORKSPACE, 2).unwrap()
+        );
+        assert!(add_member(WORKSPACE, 1).is_err());
+        assert!(add_member("[package]\n", 2).is_err());
+
+        assert_eq!(
+            "[dependencies]\nclap = \"4\"\nday1 = {path = \"../day1\"}\nday2 = {path = \"../day2\"}\n\n[dev-dependencies]\ntoml = \"0.8\"\n",
+            add_dependency(ADVENT, 2).unwrap()
+        );
+        let registry = add_solver(REGISTRY, 2).unwrap();
+        assert!(
+            registry.contains("solver::<day1::Day1>(),\n        solver::<day2::Day2>(),\n    ]")
+        );
+        assert!(registry.starts_with("pub const DAYS: &[u8] = &[1, 2];\n"));
+        assert!(add_solver(REGISTRY, 1).is_err());
+
+        // Laid out as rustfmt does once the list is too long for one line.
+        let wrapped = REGISTRY.replace("&[1]", "&[\n    1, 2,\n    3,\n]");
+        assert!(add_solver(&wrapped, 4)
+            .unwrap()
+            .starts_with("pub const DAYS: &[u8] = &[1, 2, 3, 4];\n"));
+        let numbers: Vec<String> = (1..=17).map(|day| day.to_string()).collect();
+        let long = REGISTRY.replace("&[1]", &format!("&[{}]", numbers.join(", ")));
+        assert!(add_solver(&long, 18).unwrap().starts_with(
+            "pub const DAYS: &[u8] = &[\n    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18,\n];\n"
+        ));
+        let days: Vec<u8> = (1..=29).collect();
+        assert_eq!(
+            "\n    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26,\n    27, 28, 29,\n",
+            days_list(&days)
+        );
+
+        assert_eq!(
+            "fn days(c: &mut Criterion) {\n    bench_day::<day1::Day1>(c);\n    bench_day::<day2::Day2>(c);\n}\n",
+            add_bench(BENCHES, 2).unwrap()
+        );
+
+        let fuzz = add_fuzz_target(FUZZ, 2).unwrap();
+        assert!(fuzz.contains("day1 = {path = \"../day1\"}\nday2 = {path = \"../day2\"}\n"));
+        assert!(fuzz.ends_with(
+            "\n\n[[bin]]\nname = \"day2\"\npath = \"fuzz_targets/day2.rs\"\ntest = false\ndoc = false\nbench = false\n"
+        ));
+        assert!(add_fuzz_target(FUZZ, 1).is_err());
+    }
+
+    #[test]
+    fn test_edits_of_this_workspace() {
+        let root = workspace_root();
+        let registry = read(&root.join("advent/src/registry.rs")).unwrap();
+        let day = *crate::registry::DAYS.last().unwrap() + 1;
+        assert!(add_solver(&registry, day)
+            .unwrap()
+            .contains(&format!(" {}, {}", day - 1, day)));
+
+        // Scaffolded targets look like the existing ones.
+        assert_eq!(
+            read(&root.join(format!("fuzz/fuzz_targets/day{}.rs", day - 1))).unwrap(),
+            fuzz_rs(day - 1)
+        );
+        assert!(add_bench(&read(&root.join("advent/benches/days.rs")).unwrap(), day).is_ok());
+        assert!(add_fuzz_target(&read(&root.join("fuzz/Cargo.toml")).unwrap(), day).is_ok());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = workspace("scaffold");
+
+        let written = new_day(&root, 18).unwrap();
+        assert_eq!(10, written.len());
+        assert_eq!(
+            "",
+            fs::read_to_string(root.join("day18/input.txt")).unwrap()
+        );
+        let lib = fs::read_to_string(root.join("day18/src/lib.rs")).unwrap();
+        assert!(lib.contains("impl Solution for Day18"));
+        assert!(lib.contains("const EXAMPLE"));
+        assert!(fs::read_to_string(root.join("day18/Cargo.toml"))
+            .unwrap()
+            .contains("utils = {path = \"../utils\"}"));
+        assert!(fs::read_to_string(root.join("Cargo.toml"))
+            .unwrap()
+            .contains("\"day18\","));
+        assert_eq!(
+            fuzz_rs(18),
+            fs::read_to_string(root.join("fuzz/fuzz_targets/day18.rs")).unwrap()
+        );
+        assert!(fs::read_to_string(root.join("advent/benches/days.rs"))
+            .unwrap()
+            .contains("bench_day::<day18::Day18>(c);"));
+
+        assert!(matches!(new_day(&root, 18), Err(ScaffoldError::Exists(_))));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_bad_layout_writes_nothing() {
+        let root = workspace("scaffold-layout");
+        fs::write(root.join("advent").join("src").join("registry.rs"), "").unwrap();
+
+        assert!(matches!(
+            new_day(&root, 18),
+            Err(ScaffoldError::Layout { .. })
+        ));
+        assert!(!root.join("day18").exists());
+        assert_eq!(
+            WORKSPACE,
+            fs::read_to_string(root.join("Cargo.toml")).unwrap()
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,85 @@
+//! Runs every day against its checked-in input and compares with the accepted
+//! answers recorded in `answers.toml`. Days and parts without a recorded
+//! answer, such as a freshly scaffolded day, are skipped.
+
+use std::fs;
+use std::path::Path;
+
+use toml::{Table, Value};
+use utils::input::read_input;
+use utils::Answer;
+
+fn load_answers() -> Table {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../answers.toml");
+    fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("cannot read {}: {}", path.display(), e))
+        .parse()
+        .unwrap_or_else(|e| panic!("invalid {}: {}", path.display(), e))
+}
+
+/// Compares an answer with its manifest entry. Renderings are compared line
+/// by line, ignoring trailing whitespace and the surrounding blank lines of
+/// the TOML literal.
+fn matches(answer: &Answer, expected: &Value) -> bool {
+    match (answer, expected) {
+        (Answer::Int(value), Value::Integer(expected)) => *value == *expected as i128,
+        (Answer::Text(text), Value::String(expected)) => {
+            let lines = |s: &str| -> Vec<String> {
+                s.trim_matches('\n')
+                    .lines()
+                    .map(|line| line.trim_end().to_string())
+                    .collect()
+            };
+            lines(text) == lines(expected)
+        }
+        _ => false,
+    }
+}
+
+#[test]
+fn test_accepted_answers() {
+    let answers = load_answers();
+    let mut failures: Vec<String> = Vec::new();
+
+    for solver in advent::days() {
+        let key = format!("day{}", solver.day);
+        let Some(expected) = answers.get(&key).and_then(Value::as_table) else {
+            continue;
+        };
+
+        let report =
+            match read_input(solver.default_input()).and_then(|text| solver.run(&text, &[1, 2])) {
+                Ok(report) => report,
+                Err(error) => {
+                    failures.push(format!("{}: {}", key, error));
+                    continue;
+                }
+            };
+
+        for part in report.parts {
+            let name = format!("part{}", part.part);
+            let Some(value) = expected.get(&name) else {
+                continue;
+            };
+            match part.answer {
+                Err(error) => failures.push(format!("{} {}: {}", key, name, error)),
+                Ok(answer) if !matches(&answer, value) => failures.push(format!(
+                    "{} {}: expected {}, got {}",
+                    key, name, value, answer
+                )),
+                _ => (),
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}\n", failures.join("\n"));
+}
+
+#[test]
+fn test_rendering_comparison() {
+    let expected = Value::String("\n#..\n.#.\n".to_string());
+
+    assert!(matches(&Answer::Text("#..  \n.#.".to_string()), &expected));
+    assert!(!matches(&Answer::Text("#..\n..#".to_string()), &expected));
+    assert!(!matches(&Answer::Int(3), &expected));
+}
@@ -0,0 +1,89 @@
+//! Feeds malformed inputs to every parser: each one must return an error or
+//! an input, never panic. The `fuzz` crate explores the same property with
+//! cargo-fuzz; this test keeps a deterministic sample of it in `cargo test`.
+
+use std::panic::{self, AssertUnwindSafe};
+
+use utils::input::read_input;
+
+const JUNK: &[&str] = &[
+    "",
+    "\n",
+    "\n\n\n",
+    " ",
+    "x",
+    "-",
+    "0",
+    "-1\n",
+    ",",
+    "->",
+    " -> ",
+    "=",
+    "|",
+    " | ",
+    "target area: x=",
+    "fold along x=\n",
+    "99999999999999999999999999999999\n",
+    "1,2 -> 3\n",
+    "start-end\nend-start\n",
+    "é\n",
+    "\u{0}",
+];
+
+/// Characters written over the real inputs, one position at a time.
+const MUTATIONS: &[char] = &['x', '-', '9', ' ', '\n', ',', '0'];
+
+/// How many prefixes, and how many mutated copies, of each real input are tried.
+const SAMPLES: usize = 60;
+
+/// Prefixes of `text` and copies of it with one character replaced.
+fn corruptions(text: &str) -> Vec<String> {
+    let boundaries: Vec<usize> = text.char_indices().map(|(index, _)| index).collect();
+    let step = (boundaries.len() / SAMPLES).max(1);
+
+    let mut inputs = Vec::new();
+    for &end in boundaries.iter().step_by(step) {
+        inputs.push(text[..end].to_string());
+    }
+    // Offset so that mutations do not all land on the first column.
+    for (n, &at) in boundaries.iter().skip(step / 3).step_by(step).enumerate() {
+        let replaced = text[at..].chars().next().map_or(0, char::len_utf8);
+        let mutation = MUTATIONS[n % MUTATIONS.len()];
+        inputs.push(format!(
+            "{}{}{}",
+            &text[..at],
+            mutation,
+            &text[at + replaced..]
+        ));
+    }
+    inputs
+}
+
+#[test]
+fn test_parsers_never_panic() {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut panics: Vec<String> = Vec::new();
+    for solver in advent::days() {
+        let mut inputs: Vec<String> = JUNK.iter().map(|junk| junk.to_string()).collect();
+        if let Ok(text) = read_input(solver.default_input()) {
+            inputs.extend(corruptions(&text));
+        }
+
+        for input in inputs {
+            let parsed = panic::catch_unwind(AssertUnwindSafe(|| solver.run(&input, &[])));
+            if parsed.is_err() {
+                let shown: String = input.chars().take(60).collect();
+                panics.push(format!("day{}: {:?}", solver.day, shown));
+            }
+        }
+    }
+
+    panic::set_hook(hook);
+    assert!(
+        panics.is_empty(),
+        "parsers panicked on:\n{}",
+        panics.join("\n")
+    );
+}
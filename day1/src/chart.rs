@@ -0,0 +1,231 @@
+//! Charts of the depth readings and of their sliding-window sums, as a
+//! terminal sparkline or an SVG image. Each step is coloured by whether it
+//! goes deeper, shallower or stays level, so the window comparisons counted
+//! by part 2 can be seen.
+
+use std::cmp::Ordering;
+use std::fmt::Write;
+
+const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+const GREEN: &str = "\x1b[32m";
+const RED: &str = "\x1b[31m";
+const RESET: &str = "\x1b[0m";
+
+const INCREASE: &str = "#2a9d4b";
+const DECREASE: &str = "#d1495b";
+const LEVEL: &str = "#8a8a8a";
+
+/// A depth series and the sums of its sliding windows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Chart {
+    pub depths: Vec<i64>,
+    pub window: usize,
+    pub sums: Vec<i64>,
+}
+
+impl Chart {
+    /// Panics if `window` is 0.
+    pub fn new(depths: &[i32], window: usize) -> Self {
+        let sums = depths
+            .windows(window)
+            .map(|w| w.iter().map(|&d| i64::from(d)).sum())
+            .collect();
+        Chart {
+            depths: depths.iter().map(|&d| i64::from(d)).collect(),
+            window,
+            sums,
+        }
+    }
+
+    /// Two labelled sparklines, the depths then the window sums, at most
+    /// `width` columns each. Longer series are averaged over equal buckets.
+    /// With `colour`, increases are green and decreases red.
+    pub fn sparkline(&self, width: usize, colour: bool) -> String {
+        let labels = ["depth".to_string(), format!("sum of {}", self.window)];
+        let label_width = labels.iter().map(String::len).max().unwrap_or(0);
+
+        let mut output = String::new();
+        for (label, series) in labels.iter().zip([&self.depths, &self.sums]) {
+            let _ = writeln!(
+                output,
+                "{:<w$}  {}",
+                label,
+                sparkline(&buckets(series, width), colour),
+                w = label_width
+            );
+        }
+        output
+    }
+
+    /// An SVG image of `width` x `height` pixels with the depths on top and
+    /// the window sums below, deeper readings lower down.
+    pub fn svg(&self, width: u32, height: u32) -> String {
+        let panel = f64::from(height) / 2.0;
+        let mut output = String::new();
+        let _ = writeln!(
+            output,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+            w = width,
+            h = height
+        );
+        let _ = writeln!(
+            output,
+            r#"<rect width="{}" height="{}" fill="white"/>"#,
+            width, height
+        );
+
+        let labels = ["depth".to_string(), format!("sum of {}", self.window)];
+        for (index, (label, series)) in labels.iter().zip([&self.depths, &self.sums]).enumerate() {
+            let top = panel * index as f64;
+            let _ = writeln!(
+                output,
+                r#"<text x="4" y="{:.1}" font-family="sans-serif" font-size="12">{}</text>"#,
+                top + 14.0,
+                label
+            );
+            plot(
+                &mut output,
+                series,
+                f64::from(width),
+                top + 20.0,
+                panel - 28.0,
+            );
+        }
+
+        output.push_str("</svg>\n");
+        output
+    }
+}
+
+/// `series` averaged down to at most `width` values.
+fn buckets(series: &[i64], width: usize) -> Vec<i64> {
+    if width == 0 || series.len() <= width {
+        return series.to_vec();
+    }
+    (0..width)
+        .map(|column| {
+            let bucket =
+                &series[column * series.len() / width..(column + 1) * series.len() / width];
+            bucket.iter().sum::<i64>() / bucket.len() as i64
+        })
+        .collect()
+}
+
+fn sparkline(values: &[i64], colour: bool) -> String {
+    let (Some(&min), Some(&max)) = (values.iter().min(), values.iter().max()) else {
+        return String::new();
+    };
+
+    let mut output = String::new();
+    let mut previous: Option<i64> = None;
+    for &value in values {
+        let level = if max == min {
+            0
+        } else {
+            ((value - min) * (BARS.len() as i64 - 1) / (max - min)) as usize
+        };
+        if colour {
+            output.push_str(match previous.map(|p| value.cmp(&p)) {
+                Some(Ordering::Greater) => GREEN,
+                Some(Ordering::Less) => RED,
+                _ => RESET,
+            });
+        }
+        output.push(BARS[level]);
+        previous = Some(value);
+    }
+    if colour {
+        output.push_str(RESET);
+    }
+    output
+}
+
+/// Draws `series` in the band of `height` pixels starting at `top`, as runs
+/// of steps going the same way.
+fn plot(output: &mut String, series: &[i64], width: f64, top: f64, height: f64) {
+    let (Some(&min), Some(&max)) = (series.iter().min(), series.iter().max()) else {
+        return;
+    };
+    let point = |index: usize| {
+        let x = if series.len() > 1 {
+            index as f64 * width / (series.len() - 1) as f64
+        } else {
+            width / 2.0
+        };
+        let y = if max == min {
+            top + height / 2.0
+        } else {
+            top + (series[index] - min) as f64 * height / (max - min) as f64
+        };
+        format!("{:.1},{:.1}", x, y)
+    };
+
+    let stroke = |from: usize| match series[from + 1].cmp(&series[from]) {
+        Ordering::Greater => INCREASE,
+        Ordering::Less => DECREASE,
+        Ordering::Equal => LEVEL,
+    };
+
+    let mut start = 0;
+    while start + 1 < series.len() {
+        let colour = stroke(start);
+        let mut end = start + 1;
+        while end + 1 < series.len() && stroke(end) == colour {
+            end += 1;
+        }
+        let points: Vec<String> = (start..=end).map(point).collect();
+        let _ = writeln!(
+            output,
+            r#"<polyline fill="none" stroke="{}" points="{}"/>"#,
+            colour,
+            points.join(" ")
+        );
+        start = end;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: [i32; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    #[test]
+    fn test_sparkline() {
+        let chart = Chart::new(&EXAMPLE, 3);
+        assert_eq!(vec![607, 618, 618, 617, 647, 716, 769, 792], chart.sums);
+
+        assert_eq!(
+            "depth     ▁▁▁▂▁▁▅█▇▇\nsum of 3  ▁▁▁▁▂▅▇█\n",
+            chart.sparkline(80, false)
+        );
+
+        let coloured = chart.sparkline(80, true);
+        let sums = coloured.lines().nth(1).unwrap();
+        // 618 -> 618 is level, then 617 is a decrease.
+        assert!(sums.contains(&format!("{}▁{}▁", RESET, RED)));
+    }
+
+    #[test]
+    fn test_buckets() {
+        assert_eq!(vec![1, 3, 5], buckets(&[0, 2, 2, 4, 4, 6], 3));
+        assert_eq!(vec![4, 5], buckets(&[4, 5], 3));
+        assert_eq!("", sparkline(&[], false));
+        assert_eq!("▁▁", sparkline(&[3, 3], false));
+    }
+
+    #[test]
+    fn test_svg() {
+        let svg = Chart::new(&EXAMPLE, 3).svg(400, 200);
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.ends_with("</svg>\n"));
+        assert!(svg.contains(">sum of 3</text>"));
+
+        // Depths: up, down, up, down, up; sums: up, level, down, up.
+        assert_eq!(9, svg.matches("<polyline").count());
+        assert_eq!(5, svg.matches(INCREASE).count());
+        assert_eq!(3, svg.matches(DECREASE).count());
+        assert_eq!(1, svg.matches(LEVEL).count());
+    }
+}
@@ -0,0 +1,185 @@
+//! Command line of the `day1` binary: both answers by default, or the depth
+//! profile report, or a chart of the readings.
+
+use std::error::Error;
+use std::fmt::Write;
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+
+use clap::{Parser, ValueEnum};
+use utils::input::read_source;
+use utils::solution::solve;
+use utils::Solution;
+
+use crate::chart::Chart;
+use crate::profile::DepthReport;
+use crate::Day1;
+
+const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+/// Most columns of a sparkline.
+const SPARKLINE_WIDTH: usize = 80;
+/// Width and height of the SVG image, in pixels.
+const SVG_SIZE: (u32, u32) = (800, 400);
+
+#[derive(Debug, Parser)]
+#[command(name = "day1", about = "Advent of Code 2021, day 1: Sonar Sweep")]
+pub struct Options {
+    /// Puzzle input, `-` for stdin; defaults to the day's input.txt
+    pub input: Option<PathBuf>,
+
+    /// Print the depth profile report instead of the answers
+    #[arg(long)]
+    pub report: bool,
+
+    /// Print the report as CSV, one line per compared reading
+    #[arg(long)]
+    pub csv: bool,
+
+    /// Draw the depths and their window sums instead of the answers
+    #[arg(long, value_enum, conflicts_with_all = ["report", "csv"])]
+    pub chart: Option<ChartFormat>,
+
+    /// Write the output to FILE instead of the standard output
+    #[arg(long, value_name = "FILE")]
+    pub out: Option<PathBuf>,
+
+    /// Readings in each moving average, and in each charted window sum
+    #[arg(long, default_value = "3")]
+    pub window: NonZeroUsize,
+
+    /// Standard deviations from the average beyond which a reading is an
+    /// outlier
+    #[arg(long, default_value_t = 3.0)]
+    pub threshold: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ChartFormat {
+    /// Sparklines, coloured when printed to a terminal
+    Ascii,
+    /// An SVG image
+    Svg,
+}
+
+/// Reads the input named by `options` and prints what they ask for, or
+/// writes it to `--out`.
+pub fn run(options: &Options) -> Result<(), Box<dyn Error>> {
+    let path = options
+        .input
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT));
+    let text = read_source(path)?;
+
+    let colour = options.out.is_none() && io::stdout().is_terminal();
+    let output = output(options, &text, colour)?;
+    match &options.out {
+        Some(path) => fs::write(path, output)
+            .map_err(|e| format!("cannot write {}: {}", path.display(), e))?,
+        None => print!("{}", output),
+    }
+    Ok(())
+}
+
+/// What `options` ask for, from the puzzle input `text`. With `colour`,
+/// sparklines use terminal colours.
+pub fn output(options: &Options, text: &str, colour: bool) -> Result<String, Box<dyn Error>> {
+    if let Some(format) = options.chart {
+        let chart = Chart::new(&Day1::parse(text)?, options.window.get());
+        return Ok(match format {
+            ChartFormat::Ascii => chart.sparkline(SPARKLINE_WIDTH, colour),
+            ChartFormat::Svg => chart.svg(SVG_SIZE.0, SVG_SIZE.1),
+        });
+    }
+
+    if !options.report && !options.csv {
+        let mut output = String::new();
+        for (part, answer) in solve::<Day1>(text)?.iter().enumerate() {
+            let _ = writeln!(output, "Part {} : {}", part + 1, answer);
+        }
+        return Ok(output);
+    }
+
+    let depths = Day1::parse(text)?;
+    let report = DepthReport::new(&depths, options.window.get(), options.threshold)
+        .ok_or("the input has no readings")?;
+    Ok(if options.csv {
+        report.csv()
+    } else {
+        report.table()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+    fn options(args: &[&str]) -> Options {
+        Options::try_parse_from(std::iter::once("day1").chain(args.iter().copied())).unwrap()
+    }
+
+    #[test]
+    fn test_answers() {
+        assert_eq!(
+            "Part 1 : 7\nPart 2 : 5\n",
+            output(&options(&[]), EXAMPLE, false).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_report_options() {
+        let table = output(
+            &options(&["--report", "--threshold", "1.5"]),
+            EXAMPLE,
+            false,
+        )
+        .unwrap();
+        assert!(table.starts_with("Readings      10\n"));
+        assert!(table.contains("more than 1.5 standard deviations"));
+
+        let csv = output(&options(&["--csv", "--window", "2"]), EXAMPLE, false).unwrap();
+        assert!(csv.starts_with("index,depth,average,deviations,outlier\n2,208,199.500,"));
+        assert_eq!(9, csv.lines().count());
+
+        assert!(output(&options(&["--report"]), "\n", false).is_err());
+        assert!(Options::try_parse_from(["day1", "--window", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_chart_options() {
+        let dir = std::env::temp_dir().join(format!("day1-chart-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let input = dir.join("input.txt");
+        fs::write(&input, EXAMPLE).unwrap();
+        let out = dir.join("chart");
+        let args = |format: &str| {
+            options(&[
+                input.to_str().unwrap(),
+                "--chart",
+                format,
+                "--out",
+                out.to_str().unwrap(),
+            ])
+        };
+
+        run(&args("svg")).unwrap();
+        let svg = fs::read_to_string(&out).unwrap();
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.contains(">sum of 3</text>"));
+
+        run(&args("ascii")).unwrap();
+        assert_eq!(
+            "depth     ▁▁▁▂▁▁▅█▇▇\nsum of 3  ▁▁▁▁▂▅▇█\n",
+            fs::read_to_string(&out).unwrap()
+        );
+
+        assert!(Options::try_parse_from(["day1", "--chart", "png"]).is_err());
+        assert!(Options::try_parse_from(["day1", "--chart", "svg", "--report"]).is_err());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
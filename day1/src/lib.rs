@@ -0,0 +1,144 @@
+pub mod chart;
+pub mod cli;
+pub mod profile;
+
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use utils::input::{parse_lines, InputError};
+use utils::{Answer, Solution, SolveError};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        parse_lines(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(count_increases(input.iter().copied(), 1).increases.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(count_increases(input.iter().copied(), 3).increases.into())
+    }
+}
+
+/// How the sums of consecutive windows of measurements compare.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Sweep {
+    pub increases: usize,
+    pub decreases: usize,
+    pub plateaus: usize,
+    /// The most increases in a row.
+    pub longest_run: usize,
+}
+
+/// Compares the sum of each `window` consecutive measurements with the sum of
+/// the previous window, keeping only the last `window` measurements.
+///
+/// Panics if `window` is 0.
+pub fn count_increases<I>(measures: I, window: usize) -> Sweep
+where
+    I: IntoIterator<Item = i32>,
+{
+    assert!(window > 0, "window size must be non-zero");
+
+    // Two consecutive windows share all but their first and last
+    // measurements, so comparing those compares the sums.
+    let mut last: VecDeque<i32> = VecDeque::with_capacity(window);
+    let mut sweep = Sweep::default();
+    let mut run = 0;
+
+    for measure in measures {
+        if last.len() < window {
+            last.push_back(measure);
+            continue;
+        }
+        let dropped = last.pop_front().unwrap_or_default();
+        last.push_back(measure);
+
+        match measure.cmp(&dropped) {
+            Ordering::Greater => {
+                sweep.increases += 1;
+                run += 1;
+                sweep.longest_run = sweep.longest_run.max(run);
+            }
+            Ordering::Less => {
+                sweep.decreases += 1;
+                run = 0;
+            }
+            Ordering::Equal => {
+                sweep.plateaus += 1;
+                run = 0;
+            }
+        }
+    }
+
+    sweep
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: [i32; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    #[test]
+    fn test_example() {
+        let sweep = count_increases(EXAMPLE, 1);
+        assert_eq!(
+            Sweep {
+                increases: 7,
+                decreases: 2,
+                plateaus: 0,
+                longest_run: 3,
+            },
+            sweep
+        );
+
+        // Sums 607, 618, 618, 617, 647, 716, 769, 792.
+        let sweep = count_increases(EXAMPLE, 3);
+        assert_eq!(
+            Sweep {
+                increases: 5,
+                decreases: 1,
+                plateaus: 1,
+                longest_run: 4,
+            },
+            sweep
+        );
+    }
+
+    #[test]
+    fn test_zero_and_short_input() {
+        assert_eq!(1, count_increases([0, 0, 1], 1).increases);
+        assert_eq!(1, count_increases([-1, 0], 1).increases);
+        assert_eq!(Sweep::default(), count_increases([1, 2, 3], 3));
+        assert_eq!(Sweep::default(), count_increases(Vec::new(), 2));
+    }
+
+    #[test]
+    fn test_windows_match_sums() {
+        let measures: Vec<i32> = (0..500).map(|n| (n * 37 % 101) - 50).collect();
+        for window in 1..8 {
+            let sums: Vec<i32> = measures.windows(window).map(|w| w.iter().sum()).collect();
+            let expected = sums.windows(2).filter(|pair| pair[1] > pair[0]).count();
+            assert_eq!(
+                expected,
+                count_increases(measures.iter().copied(), window).increases
+            );
+        }
+    }
+
+    #[test]
+    fn test_streaming() {
+        let sweep = count_increases((0..1_000_000).map(|n| n % 4), 2);
+        assert_eq!(
+            1_000_000 - 2,
+            sweep.increases + sweep.decreases + sweep.plateaus
+        );
+    }
+}
@@ -0,0 +1,333 @@
+//! Statistics of the depth profile: extremes, moving averages, and the
+//! readings that stand out from the ones before them.
+
+use std::fmt::Write;
+
+/// A change of depth between two consecutive readings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Jump {
+    /// Index of the reading after the jump.
+    pub index: usize,
+    pub from: i32,
+    pub to: i32,
+}
+
+impl Jump {
+    pub fn size(&self) -> i64 {
+        i64::from(self.to) - i64::from(self.from)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DepthStats {
+    pub count: usize,
+    pub min: i32,
+    pub max: i32,
+    pub mean: f64,
+    /// The largest change, up or down, between consecutive readings.
+    pub largest_jump: Option<Jump>,
+}
+
+/// Statistics of `depths`, or `None` when there are no readings.
+pub fn stats(depths: &[i32]) -> Option<DepthStats> {
+    let min = *depths.iter().min()?;
+    let max = *depths.iter().max()?;
+    let sum: i64 = depths.iter().map(|&depth| i64::from(depth)).sum();
+
+    let largest_jump = depths
+        .windows(2)
+        .enumerate()
+        .map(|(index, pair)| Jump {
+            index: index + 1,
+            from: pair[0],
+            to: pair[1],
+        })
+        .max_by_key(|jump| (jump.size().abs(), std::cmp::Reverse(jump.index)));
+
+    Some(DepthStats {
+        count: depths.len(),
+        min,
+        max,
+        mean: sum as f64 / depths.len() as f64,
+        largest_jump,
+    })
+}
+
+/// The mean of every `window` consecutive readings, in order.
+///
+/// Panics if `window` is 0.
+pub fn moving_averages(depths: &[i32], window: usize) -> Vec<f64> {
+    assert!(window > 0, "window size must be non-zero");
+
+    let mut averages = Vec::with_capacity(depths.len().saturating_sub(window - 1));
+    let mut sum: i64 = depths.iter().take(window).map(|&d| i64::from(d)).sum();
+    if depths.len() >= window {
+        averages.push(sum as f64 / window as f64);
+    }
+    for (index, &depth) in depths.iter().enumerate().skip(window) {
+        sum += i64::from(depth) - i64::from(depths[index - window]);
+        averages.push(sum as f64 / window as f64);
+    }
+    averages
+}
+
+/// A reading compared with the average of the readings before it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Deviation {
+    pub index: usize,
+    pub depth: i32,
+    /// Average of the `window` previous readings.
+    pub average: f64,
+    /// Distance from the average, in standard deviations of all the distances.
+    pub deviations: f64,
+}
+
+/// Compares every reading from index `window` on with the average of the
+/// `window` readings before it.
+fn deviations(depths: &[i32], window: usize) -> Vec<Deviation> {
+    let averages = moving_averages(depths, window);
+    let distances: Vec<f64> = depths
+        .iter()
+        .skip(window)
+        .zip(&averages)
+        .map(|(&depth, average)| f64::from(depth) - average)
+        .collect();
+
+    let count = distances.len() as f64;
+    let mean = distances.iter().sum::<f64>() / count;
+    let sigma = (distances.iter().map(|d| (d - mean).powi(2)).sum::<f64>() / count).sqrt();
+
+    distances
+        .iter()
+        .zip(&averages)
+        .enumerate()
+        .map(|(n, (distance, &average))| Deviation {
+            index: n + window,
+            depth: depths[n + window],
+            average,
+            deviations: if sigma > 0.0 { distance / sigma } else { 0.0 },
+        })
+        .collect()
+}
+
+/// Readings further than `threshold` standard deviations from the average of
+/// the `window` readings before them.
+///
+/// Panics if `window` is 0.
+pub fn outliers(depths: &[i32], window: usize, threshold: f64) -> Vec<Deviation> {
+    deviations(depths, window)
+        .into_iter()
+        .filter(|deviation| deviation.deviations.abs() > threshold)
+        .collect()
+}
+
+/// Moving averages on each line of the table, after the index of the first.
+const AVERAGES_PER_LINE: usize = 8;
+
+/// Statistics, moving averages and outliers of a depth profile, ready to
+/// print.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DepthReport {
+    pub stats: DepthStats,
+    pub window: usize,
+    pub threshold: f64,
+    /// The moving averages of `window` readings.
+    pub averages: Vec<f64>,
+    /// One entry per reading that has `window` readings before it.
+    pub deviations: Vec<Deviation>,
+}
+
+impl DepthReport {
+    /// The report of `depths`, or `None` when there are no readings.
+    ///
+    /// Panics if `window` is 0.
+    pub fn new(depths: &[i32], window: usize, threshold: f64) -> Option<Self> {
+        Some(DepthReport {
+            stats: stats(depths)?,
+            window,
+            threshold,
+            averages: moving_averages(depths, window),
+            deviations: deviations(depths, window),
+        })
+    }
+
+    pub fn outliers(&self) -> impl Iterator<Item = &Deviation> {
+        self.deviations
+            .iter()
+            .filter(|deviation| deviation.deviations.abs() > self.threshold)
+    }
+
+    /// The statistics, the moving averages, then a table of the outliers.
+    pub fn table(&self) -> String {
+        let stats = &self.stats;
+        let mut output = String::new();
+        let _ = writeln!(output, "Readings      {}", stats.count);
+        let _ = writeln!(output, "Min           {}", stats.min);
+        let _ = writeln!(output, "Max           {}", stats.max);
+        let _ = writeln!(output, "Mean          {:.2}", stats.mean);
+        if let Some(jump) = stats.largest_jump {
+            let _ = writeln!(
+                output,
+                "Largest jump  {:+} at {} ({} -> {})",
+                jump.size(),
+                jump.index,
+                jump.from,
+                jump.to
+            );
+        }
+
+        let _ = writeln!(output, "\nMoving averages of {} readings:", self.window);
+        if self.averages.is_empty() {
+            let _ = writeln!(output, "none");
+        }
+        let index_width = self.averages.len().saturating_sub(1).to_string().len();
+        let width = self
+            .averages
+            .iter()
+            .map(|average| format!("{:.2}", average).len())
+            .max()
+            .unwrap_or(0);
+        for (line, averages) in self.averages.chunks(AVERAGES_PER_LINE).enumerate() {
+            let _ = write!(output, "{:>w$}", line * AVERAGES_PER_LINE, w = index_width);
+            for average in averages {
+                let _ = write!(output, "  {:>w$.2}", average, w = width);
+            }
+            output.push('\n');
+        }
+
+        let _ = writeln!(
+            output,
+            "\nOutliers, more than {} standard deviations from the average of the {} previous readings:",
+            self.threshold, self.window
+        );
+        let rows: Vec<[String; 4]> = self
+            .outliers()
+            .map(|outlier| {
+                [
+                    outlier.index.to_string(),
+                    outlier.depth.to_string(),
+                    format!("{:.2}", outlier.average),
+                    format!("{:+.2}", outlier.deviations),
+                ]
+            })
+            .collect();
+        if rows.is_empty() {
+            let _ = writeln!(output, "none");
+            return output;
+        }
+
+        let header = ["Index", "Depth", "Average", "Deviations"];
+        let mut widths = header.map(str::len);
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row.iter()) {
+                *width = (*width).max(cell.len());
+            }
+        }
+        for row in std::iter::once(header.map(String::from)).chain(rows) {
+            let _ = writeln!(
+                output,
+                "{:>w0$}  {:>w1$}  {:>w2$}  {:>w3$}",
+                row[0],
+                row[1],
+                row[2],
+                row[3],
+                w0 = widths[0],
+                w1 = widths[1],
+                w2 = widths[2],
+                w3 = widths[3],
+            );
+        }
+        output
+    }
+
+    /// One line per compared reading:
+    /// `index,depth,average,deviations,outlier`.
+    pub fn csv(&self) -> String {
+        let mut output = String::from("index,depth,average,deviations,outlier\n");
+        for deviation in &self.deviations {
+            let _ = writeln!(
+                output,
+                "{},{},{:.3},{:.3},{}",
+                deviation.index,
+                deviation.depth,
+                deviation.average,
+                deviation.deviations,
+                deviation.deviations.abs() > self.threshold
+            );
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: [i32; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    #[test]
+    fn test_stats() {
+        let summary = stats(&EXAMPLE).unwrap();
+        assert_eq!((10, 199, 269), (summary.count, summary.min, summary.max));
+        assert!((summary.mean - 225.6).abs() < 1e-9);
+        assert_eq!(
+            Some(Jump {
+                index: 6,
+                from: 207,
+                to: 240
+            }),
+            summary.largest_jump
+        );
+
+        assert_eq!(None, stats(&[]));
+        assert_eq!(None, stats(&[5]).unwrap().largest_jump);
+    }
+
+    #[test]
+    fn test_moving_averages() {
+        assert_eq!(vec![199.5, 204.0, 209.0], moving_averages(&EXAMPLE[..4], 2));
+        assert_eq!(EXAMPLE.len() - 2, moving_averages(&EXAMPLE, 3).len());
+        assert!(moving_averages(&EXAMPLE, 11).is_empty());
+    }
+
+    #[test]
+    fn test_outliers() {
+        let mut depths: Vec<i32> = (0..50).map(|n| 100 + n % 3).collect();
+        depths[30] = 160;
+
+        let found = outliers(&depths, 5, 3.0);
+        assert_eq!(vec![30], found.iter().map(|o| o.index).collect::<Vec<_>>());
+        assert!(found[0].deviations > 3.0);
+
+        assert!(outliers(&[7; 20], 3, 1.0).is_empty());
+    }
+
+    #[test]
+    fn test_report_output() {
+        let report = DepthReport::new(&EXAMPLE, 3, 1.5).unwrap();
+        assert_eq!(7, report.deviations.len());
+
+        let csv = report.csv();
+        assert_eq!(8, csv.lines().count());
+        assert!(csv.starts_with("index,depth,average,deviations,outlier\n3,210,202.333,"));
+
+        let table = report.table();
+        assert!(table.contains("Largest jump  +33 at 6 (207 -> 240)"));
+        assert!(table.contains(
+            "Moving averages of 3 readings:\n\
+             0  202.33  206.00  206.00  205.67  215.67  238.67  256.33  264.00\n"
+        ));
+        assert!(DepthReport::new(&EXAMPLE, 11, 1.5)
+            .unwrap()
+            .table()
+            .contains("Moving averages of 11 readings:\nnone\n"));
+        let outliers = report.outliers().count();
+        assert_eq!(
+            outliers + 1,
+            table
+                .lines()
+                .skip_while(|l| !l.starts_with("Index"))
+                .count()
+        );
+    }
+}
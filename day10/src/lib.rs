@@ -0,0 +1,211 @@
+use utils::{Answer, InputError, Solution, SolveError};
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum LineState {
+    /// The closing characters that would complete the line, innermost first.
+    Incomplete(Vec<char>),
+    /// The first closing character that does not match its opening one.
+    Corrupted(char),
+    Invalid,
+    Valid,
+}
+
+fn match_closing_char(opening: char, closing: char) -> bool {
+    matches!(
+        (opening, closing),
+        ('(', ')') | ('[', ']') | ('{', '}') | ('<', '>')
+    )
+}
+
+fn get_closing_char(opening: char) -> char {
+    match opening {
+        '(' => ')',
+        '[' => ']',
+        '{' => '}',
+        '<' => '>',
+        _ => '*',
+    }
+}
+
+/// Checks the brackets of a line of the navigation subsystem.
+pub fn check_line_state(line: String) -> LineState {
+    let mut opening: Vec<char> = Vec::new();
+
+    for line_char in line.chars() {
+        match line_char {
+            '(' | '[' | '{' | '<' => opening.push(line_char),
+            ')' | ']' | '}' | '>' => {
+                match opening.pop() {
+                    Some(opening) => {
+                        if !match_closing_char(opening, line_char) {
+                            return LineState::Corrupted(line_char);
+                        }
+                    }
+                    None => return LineState::Incomplete(Vec::new()),
+                };
+            }
+            _ => return LineState::Invalid,
+        }
+    }
+
+    if !opening.is_empty() {
+        let mut closing: Vec<char> = Vec::new();
+        for item in opening {
+            closing.push(get_closing_char(item))
+        }
+        closing.reverse();
+        return LineState::Incomplete(closing);
+    }
+    LineState::Valid
+}
+
+fn get_char_points(input: char) -> i32 {
+    match input {
+        ')' => 3,
+        ']' => 57,
+        '}' => 1197,
+        '>' => 25137,
+        _ => 0,
+    }
+}
+
+fn get_closing_point(closing: Vec<char>) -> i64 {
+    let mut score: i64 = 0;
+
+    for input in closing {
+        score *= 5;
+        score += get_char_points2(input);
+    }
+
+    score
+}
+
+fn get_char_points2(input: char) -> i64 {
+    match input {
+        ')' => 1,
+        ']' => 2,
+        '}' => 3,
+        '>' => 4,
+        _ => 0,
+    }
+}
+
+fn compute_scores(lines: Vec<String>) -> (i32, Option<i64>) {
+    let mut score_part1 = 0;
+    let mut score_part2: Vec<i64> = Vec::new();
+    for line in lines {
+        match check_line_state(line) {
+            LineState::Corrupted(invalid_char) => score_part1 += get_char_points(invalid_char),
+            LineState::Incomplete(ending) => score_part2.push(get_closing_point(ending)),
+            _ => (),
+        }
+    }
+
+    score_part2.sort();
+
+    let middle_index = (score_part2.len() as i32 / 2) as usize;
+
+    let score_2: Option<i64> = score_part2.get(middle_index).copied();
+
+    (score_part1, score_2)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        let lines: Vec<String> = input.lines().map(String::from).collect();
+
+        for (index, line) in lines.iter().enumerate() {
+            if let Some(column) = line.find(|c| !"()[]{}<>".contains(c)) {
+                return Err(InputError::parse(index + 1, column + 1, "not a bracket"));
+            }
+        }
+        Ok(lines)
+    }
+
+    fn part1(lines: &Self::Input) -> Result<Answer, SolveError> {
+        let (part1, _) = compute_scores(lines.clone());
+        Ok(part1.into())
+    }
+
+    fn part2(lines: &Self::Input) -> Result<Answer, SolveError> {
+        match compute_scores(lines.clone()) {
+            (_, Some(part2)) => Ok(part2.into()),
+            (_, None) => Err(SolveError::new("no incomplete line")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(2, Day10::parse("[<>]\n({)\n").unwrap().len());
+        assert!(matches!(
+            Day10::parse("[<>]\n({x)\n"),
+            Err(InputError::Parse {
+                line: 2,
+                column: 3,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_line_corrupted() {
+        let line: String = "[({(<(())[]>[[{[]{<()<>>".to_string();
+        assert_eq!(
+            LineState::Incomplete(vec!['}', '}', ']', ']', ')', '}', ')', ']']),
+            check_line_state(line)
+        );
+
+        let line: String = "{([(<{}[<>[]}>{[]{[(<()>".to_string();
+        assert_eq!(LineState::Corrupted('}'), check_line_state(line));
+
+        let line: String = "[<>({}){}[([])<>]]".to_string();
+        assert_eq!(LineState::Valid, check_line_state(line));
+
+        let line: String = "[<>({}){}[([])<>]])".to_string();
+        assert_eq!(LineState::Incomplete(Vec::new()), check_line_state(line));
+
+        let line: String = "[<>({}){}[([])<>]]*".to_string();
+        assert_eq!(LineState::Invalid, check_line_state(line));
+    }
+
+    #[test]
+    fn test_closing_score() {
+        let chars = vec![']', ')', '}', '>'];
+
+        assert_eq!(294, get_closing_point(chars));
+
+        let chars = vec!['}', '}', ']', ']', ')', '}', ')', ']'];
+
+        assert_eq!(288957, get_closing_point(chars));
+    }
+
+    #[test]
+    fn test_part1() {
+        let lines: Vec<String> = vec![
+            "[({(<(())[]>[[{[]{<()<>>".to_string(),
+            "[(()[<>])]({[<{<<[]>>(".to_string(),
+            "{([(<{}[<>[]}>{[]{[(<()>".to_string(),
+            "(((({<>}<{<{<>}{[]{[]{}".to_string(),
+            "[[<[([]))<([[{}[[()]]]".to_string(),
+            "[{[{({}]{}}([{[{{{}}([]".to_string(),
+            "{<[[]]>}<{[{[{[]{()[[[]".to_string(),
+            "[<(<(<(<{}))><([]([]()".to_string(),
+            "<{([([[(<>()){}]>(<<{{".to_string(),
+            "<{([{{}}[<[[[<>{}]]]>[]]".to_string(),
+        ];
+
+        let (part1, part2) = compute_scores(lines);
+        assert_eq!(26397, part1);
+        assert_eq!(Some(288957), part2);
+    }
+}
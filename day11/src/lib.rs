@@ -0,0 +1,142 @@
+use utils::input::InputError;
+use utils::{Answer, Grid, Solution, SolveError};
+
+pub type MatrixOctopus = Grid<u32>;
+
+fn step(matrice: &mut MatrixOctopus) -> i32 {
+    for y in 0..matrice.height() {
+        for x in 0..matrice.width() {
+            incr_energy(x, y, matrice);
+        }
+    }
+    let mut nb_changed = 0;
+    for y in 0..matrice.height() {
+        for x in 0..matrice.width() {
+            if matrice[(x, y)] > 9 {
+                nb_changed += 1;
+                matrice[(x, y)] = 0;
+            }
+        }
+    }
+    nb_changed
+}
+
+fn incr_energy(x: usize, y: usize, matrice: &mut MatrixOctopus) {
+    let value = matrice[(x, y)];
+    if value < 9 {
+        matrice[(x, y)] = value + 1;
+    } else if value == 9 {
+        matrice[(x, y)] = 10;
+
+        let neighbours: Vec<(usize, usize)> = matrice.neighbours8(x, y).collect();
+        for (x, y) in neighbours {
+            incr_energy(x, y, matrice);
+        }
+    }
+}
+
+fn is_synchronised(matrice: &MatrixOctopus) -> bool {
+    let value = matrice[(0, 0)];
+    matrice.iter().all(|(_, energy)| *energy == value)
+}
+
+fn parse_input(input: &str) -> Result<MatrixOctopus, InputError> {
+    let grid: MatrixOctopus = input.parse()?;
+    if grid.width() == 0 {
+        return Err(InputError::parse(1, 1, "no octopus in the grid"));
+    }
+    Ok(grid)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = MatrixOctopus;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        parse_input(input)
+    }
+
+    fn part1(matrice: &Self::Input) -> Result<Answer, SolveError> {
+        let mut matrice = matrice.clone();
+        let mut count = 0;
+
+        for _i in 0..100 {
+            count += step(&mut matrice);
+        }
+        Ok(count.into())
+    }
+
+    fn part2(matrice: &Self::Input) -> Result<Answer, SolveError> {
+        let mut matrice = matrice.clone();
+        let mut count = 0;
+
+        while !is_synchronised(&matrice) {
+            step(&mut matrice);
+            count += 1;
+        }
+        Ok(count.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let mut matrice: MatrixOctopus = parse_input(
+            "5483143223\n\
+             2745854711\n\
+             5264556173\n\
+             6141336146\n\
+             6357385478\n\
+             4167524645\n\
+             2176841721\n\
+             6882881134\n\
+             4846848554\n\
+             5283751526\n",
+        )
+        .unwrap();
+
+        let mut count = 0;
+
+        for _i in 0..100 {
+            count += step(&mut matrice);
+        }
+
+        assert_eq!(1656, count);
+    }
+
+    #[test]
+    fn test_step() {
+        let mut before: MatrixOctopus = parse_input(
+            "11111\n\
+             19991\n\
+             19191\n\
+             19991\n\
+             11111\n",
+        )
+        .unwrap();
+
+        let after: MatrixOctopus = parse_input(
+            "34543\n\
+             40004\n\
+             50005\n\
+             40004\n\
+             34543\n",
+        )
+        .unwrap();
+
+        println!("before {:?}", before);
+
+        let changed = step(&mut before);
+        assert_eq!(9, changed);
+        assert_eq!(before, after);
+
+        for _i in 0..100 {
+            step(&mut before);
+        }
+    }
+}
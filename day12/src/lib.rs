@@ -0,0 +1,282 @@
+use std::collections::{HashMap, HashSet};
+use utils::input::InputError;
+use utils::{Answer, Solution, SolveError};
+
+/// Splits an edge like `start-A`. Errors carry the column of the field that
+/// is wrong.
+fn parse_line(input: String) -> Result<(String, String), (usize, String)> {
+    let trimmed = input.trim();
+    let indent = input.len() - input.trim_start().len();
+    let error = |at: usize| {
+        let column = input[..indent + at].chars().count() + 1;
+        let message = format!("expected an edge like \"start-A\", found {:?}", input);
+        Err((column, message))
+    };
+
+    match trimmed.split_once('-') {
+        None | Some(("", _)) => error(0),
+        Some((start, "")) => error(start.len() + 1),
+        Some((start, end)) => match end.find('-') {
+            Some(at) => error(start.len() + 1 + at),
+            None => Ok((start.to_string(), end.to_string())),
+        },
+    }
+}
+
+pub type Graph = HashMap<String, Vec<String>>;
+
+fn parse_input(input: Vec<String>) -> Result<Graph, InputError> {
+    let mut cave: Graph = HashMap::new();
+
+    for (index, line) in input.into_iter().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let (begin_str, end_str) = parse_line(line.clone())
+            .map_err(|(column, e)| InputError::parse(index + 1, column, e))?;
+        // Paths could go back and forth between two big caves forever.
+        if !is_lower(&begin_str) && !is_lower(&end_str) {
+            let column = line.chars().take_while(|c| c.is_whitespace()).count() + 1;
+            return Err(InputError::parse(
+                index + 1,
+                column,
+                format!("{} and {} are both big caves", begin_str, end_str),
+            ));
+        }
+
+        match cave.get_mut(&begin_str) {
+            Some(value) => {
+                if end_str != "start" {
+                    value.push(end_str.clone());
+                }
+            }
+            None => {
+                if end_str != "start" {
+                    cave.insert(begin_str.clone(), vec![end_str.clone()]);
+                }
+            }
+        };
+
+        match cave.get_mut(&end_str) {
+            Some(value) => {
+                if begin_str != "start" {
+                    value.push(begin_str);
+                }
+            }
+            None => {
+                if begin_str != "start" {
+                    cave.insert(end_str, vec![begin_str]);
+                }
+            }
+        };
+    }
+
+    Ok(cave)
+}
+
+fn is_lower(input: &str) -> bool {
+    for c in input.chars() {
+        if c.is_uppercase() {
+            return false;
+        }
+    }
+    true
+}
+
+fn calc_path(graph: &Graph, visited: HashSet<String>, current_node: &String, part2: bool) -> i32 {
+    let mut result: i32 = 0;
+
+    if current_node == "end" {
+        return 1;
+    }
+
+    // A cave only linked to `start` has no way out, and no entry.
+    for node in graph.get(current_node).into_iter().flatten() {
+        let mut sub_visited = visited.clone();
+        if is_lower(node) {
+            sub_visited.insert(node.clone());
+            if !visited.contains(node) {
+                result += calc_path(graph, sub_visited, node, part2);
+            } else if part2 {
+                result += calc_path(graph, sub_visited, node, false);
+            }
+        } else {
+            result += calc_path(graph, sub_visited, node, part2);
+        }
+    }
+    result
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Graph;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        parse_input(input.lines().map(String::from).collect())
+    }
+
+    fn part1(graph: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(calc_path_from_start(graph, false).into())
+    }
+
+    fn part2(graph: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(calc_path_from_start(graph, true).into())
+    }
+}
+
+fn calc_path_from_start(graph: &Graph, part2: bool) -> i32 {
+    let mut start_set: HashSet<String> = HashSet::new();
+    start_set.insert("start".to_string());
+    calc_path(graph, start_set, &"start".to_string(), part2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use utils::gen;
+
+    #[test]
+    fn test_parse_line() {
+        let input = "fs-end".to_string();
+        assert_eq!(Ok(("fs".to_string(), "end".to_string())), parse_line(input));
+        assert!(parse_line("fs".to_string()).is_err());
+        assert!(parse_line("fs-end-A".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_parse_input() {
+        //let input: Vec<String> = vec!["start-A".to_string(), "A-end".to_string()];
+
+        let mut expected: HashMap<String, Vec<String>> = HashMap::new();
+        expected.insert("start".to_string(), vec!["A".to_string()]);
+        expected.insert(
+            "A".to_string(),
+            vec!["start".to_string(), "end".to_string()],
+        );
+        expected.insert("end".to_string(), vec!["A".to_string()]);
+    }
+
+    #[test]
+    fn test_calc_input() {
+        let input: Vec<String> = vec![
+            "dc-end".to_string(),
+            "HN-start".to_string(),
+            "start-kj".to_string(),
+            "dc-start".to_string(),
+            "dc-HN".to_string(),
+            "LN-dc".to_string(),
+            "HN-end".to_string(),
+            "kj-sa".to_string(),
+            "kj-HN".to_string(),
+            "kj-dc".to_string(),
+        ];
+
+        let input2 = vec![
+            "start-A".to_string(),
+            "start-b".to_string(),
+            "A-c".to_string(),
+            "A-b".to_string(),
+            "b-d".to_string(),
+            "A-end".to_string(),
+            "b-end".to_string(),
+        ];
+
+        let mut start_set: HashSet<String> = HashSet::new();
+        start_set.insert("start".to_string());
+
+        let res = calc_path(
+            &parse_input(input2.clone()).unwrap(),
+            start_set.clone(),
+            &"start".to_string(),
+            false,
+        );
+        assert_eq!(10, res);
+
+        let res = calc_path(
+            &parse_input(input).unwrap(),
+            start_set.clone(),
+            &"start".to_string(),
+            false,
+        );
+        assert_eq!(19, res);
+
+        let res = calc_path(
+            &parse_input(input2.clone()).unwrap(),
+            start_set.clone(),
+            &"start".to_string(),
+            true,
+        );
+        assert_eq!(36, res);
+        //assert_eq!(10, calc_path_from_start(&parse_input(input2)));
+        //assert_eq!(19, calc_path_from_start(&parse_input(input)));
+    }
+
+    #[test]
+    fn test_parse_bad_edge() {
+        assert!(matches!(
+            Day12::parse("start-A\nA end\n"),
+            Err(InputError::Parse { line: 2, .. })
+        ));
+
+        assert!(matches!(
+            Day12::parse("start-A\nA-b\nA-end\nA-B\nB-end\n"),
+            Err(InputError::Parse {
+                line: 4,
+                column: 1,
+                ..
+            })
+        ));
+
+        assert_eq!(1, parse_line("fs".to_string()).unwrap_err().0);
+        assert_eq!(3, parse_line("  -end".to_string()).unwrap_err().0);
+        assert_eq!(4, parse_line("fs-".to_string()).unwrap_err().0);
+        assert_eq!(7, parse_line("fs-end-A".to_string()).unwrap_err().0);
+        assert_eq!(4, parse_line("é-b-c".to_string()).unwrap_err().0);
+    }
+
+    /// Number of paths to `end` continuing `path`, walking the list of
+    /// edges directly. With `revisit`, one small cave may be entered twice.
+    fn count_paths<'a>(
+        edges: &[(&'a str, &'a str)],
+        path: &mut Vec<&'a str>,
+        revisit: bool,
+    ) -> usize {
+        let current = *path.last().unwrap();
+        if current == "end" {
+            return 1;
+        }
+
+        let mut count = 0;
+        for &(a, b) in edges {
+            let next = match (a == current, b == current) {
+                (true, _) => b,
+                (_, true) => a,
+                _ => continue,
+            };
+            let seen = next.chars().all(char::is_lowercase) && path.contains(&next);
+            if next == "start" || (seen && !revisit) {
+                continue;
+            }
+            path.push(next);
+            count += count_paths(edges, path, revisit && !seen);
+            path.pop();
+        }
+        count
+    }
+
+    proptest! {
+        #[test]
+        fn test_paths_match_edge_walk(text in gen::cave_graph()) {
+            let graph = Day12::parse(&text).unwrap();
+            let edges: Vec<(&str, &str)> = text.lines().filter_map(|l| l.split_once('-')).collect();
+
+            let part1 = count_paths(&edges, &mut vec!["start"], false);
+            let part2 = count_paths(&edges, &mut vec!["start"], true);
+            prop_assert_eq!(Ok(Answer::from(part1)), Day12::part1(&graph));
+            prop_assert_eq!(Ok(Answer::from(part2)), Day12::part2(&graph));
+        }
+    }
+}
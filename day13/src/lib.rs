@@ -0,0 +1,259 @@
+use utils::{Answer, Grid, InputError, Solution, SolveError};
+
+pub type DotMatrix = Grid<bool>;
+
+/// Largest sheet accepted, in dots; the puzzle sheets are about 1300 x 900.
+const MAX_DOTS: usize = 1 << 24;
+
+fn parse_input(lines: Vec<String>) -> Result<(DotMatrix, Vec<(String, usize)>), InputError> {
+    let mut coordonates: Vec<(usize, usize)> = Vec::new();
+
+    let mut instructions: Vec<(String, usize)> = Vec::new();
+
+    let mut xmax: usize = 0;
+    let mut ymax: usize = 0;
+
+    for (index, line) in lines.iter().enumerate() {
+        let error = |column: usize, message: &str| InputError::parse(index + 1, column, message);
+
+        if let Some(raw_instr) = line.strip_prefix("fold along ") {
+            //instruction
+            let at = line.chars().count() - raw_instr.chars().count() + 1;
+            let (axis, value) = raw_instr
+                .split_once('=')
+                .ok_or_else(|| error(at, "expected \"fold along x=N\""))?;
+            if axis != "x" && axis != "y" {
+                return Err(error(at, "fold axis must be x or y"));
+            }
+            let value: usize = value
+                .parse()
+                .map_err(|_| error(at + axis.chars().count() + 1, "invalid fold position"))?;
+
+            instructions.push((axis.to_string(), value));
+        } else if !line.is_empty() {
+            //coordonnée
+            let (x_value, y_value) = line
+                .split_once(',')
+                .ok_or_else(|| error(1, "expected \"x,y\""))?;
+            let y_column = x_value.chars().count() + 2;
+            let x_value: usize = x_value.parse().map_err(|_| error(1, "invalid x"))?;
+            let y_value: usize = y_value.parse().map_err(|_| error(y_column, "invalid y"))?;
+
+            xmax = xmax.max(x_value);
+            ymax = ymax.max(y_value);
+            let dots = xmax.checked_add(1).zip(ymax.checked_add(1));
+            if !matches!(dots, Some((w, h)) if w.checked_mul(h).is_some_and(|n| n <= MAX_DOTS)) {
+                return Err(error(1, "the sheet is too large"));
+            }
+
+            coordonates.push((x_value, y_value));
+        }
+    }
+
+    let mut matrix: DotMatrix = Grid::new(xmax + 1, ymax + 1, false);
+
+    for coord in coordonates {
+        matrix[coord] = true;
+    }
+
+    Ok((matrix, instructions))
+}
+
+/// Folds `matrix`, failing when the fold line is outside of the sheet.
+fn checked_fold(matrix: &DotMatrix, axis: &str, value: usize) -> Result<DotMatrix, SolveError> {
+    let size = if axis == "x" {
+        matrix.width()
+    } else {
+        matrix.height()
+    };
+    if value >= size {
+        return Err(SolveError::new(format!(
+            "fold along {}={} is outside of the sheet",
+            axis, value
+        )));
+    }
+    Ok(fold_matrix(matrix, axis.to_string(), value))
+}
+
+/// Folds the bottom half up along `y = value`, or the right half left along
+/// `x = value`.
+fn fold_matrix(matrix: &DotMatrix, axis: String, value: usize) -> DotMatrix {
+    /*
+    ...#..#..#
+    ....#.....
+    ..........
+    #.........
+    ...#....#.
+    ..........
+    ..........
+    ----------
+    ..........
+    ..........
+    .#....#.##
+    ....#.....
+    ......#...
+    #.........
+
+    (0, 14) -> (0, 0)
+
+    value = 7
+    y = 14
+
+    new y = value - (y - value)
+    new y = 7 - (14 -7 ) = 7 - 7 = 0
+
+    value = 7
+    y = 10
+
+    new y = value - (y - value)
+    new y = 7 - (10 - 7) = 7 - 3 = 4
+
+    */
+    let (width, height) = if axis == "x" {
+        (value, matrix.height())
+    } else {
+        (matrix.width(), value)
+    };
+
+    Grid::from_fn(width, height, |x, y| {
+        let (mirror_x, mirror_y) = if axis == "x" {
+            (value + (value - x), y)
+        } else {
+            (x, value + (value - y))
+        };
+        matrix.get(x, y) == Some(&true) || matrix.get(mirror_x, mirror_y) == Some(&true)
+    })
+}
+
+fn calc_dots(matrix: &DotMatrix) -> usize {
+    matrix.iter().filter(|(_, dot)| **dot).count()
+}
+
+fn render_matrix(matrice: &DotMatrix) -> String {
+    matrice.render(|&dot| if dot { '#' } else { '.' })
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = (DotMatrix, Vec<(String, usize)>);
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        parse_input(input.lines().map(String::from).collect())
+    }
+
+    fn part1((matrix, instructions): &Self::Input) -> Result<Answer, SolveError> {
+        let (axis, value) = instructions
+            .first()
+            .ok_or_else(|| SolveError::new("no fold instruction"))?;
+        let result = checked_fold(matrix, axis, *value)?;
+        Ok(calc_dots(&result).into())
+    }
+
+    fn part2((matrix, instructions): &Self::Input) -> Result<Answer, SolveError> {
+        let mut result = matrix.clone();
+
+        for (axis, value) in instructions.iter() {
+            result = checked_fold(&result, axis, *value)?;
+        }
+
+        Ok(render_matrix(&result).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_() {
+        let lines: Vec<String> = vec![
+            "6,10".to_string(),
+            "0,14".to_string(),
+            "9,10".to_string(),
+            "0,3".to_string(),
+            "10,4".to_string(),
+            "4,11".to_string(),
+            "6,0".to_string(),
+            "6,12".to_string(),
+            "4,1".to_string(),
+            "0,13".to_string(),
+            "10,12".to_string(),
+            "3,4".to_string(),
+            "3,0".to_string(),
+            "8,4".to_string(),
+            "1,10".to_string(),
+            "2,14".to_string(),
+            "8,10".to_string(),
+            "9,0".to_string(),
+            "".to_string(),
+            "fold along y=7".to_string(),
+            "fold along x=5".to_string(),
+        ];
+
+        let (matrix, instructions) = parse_input(lines).unwrap();
+
+        assert_eq!(
+            vec![("y".to_string(), 7_usize), ("x".to_string(), 5_usize)],
+            instructions
+        );
+
+        let result = fold_matrix(&matrix, "y".to_string(), 7);
+
+        let res = calc_dots(&result);
+        assert_eq!(17, res);
+    }
+
+    #[test]
+    fn test_render() {
+        let (matrix, instructions) = Day13::parse(
+            "6,10\n0,14\n9,10\n0,3\n10,4\n4,11\n6,0\n6,12\n4,1\n0,13\n\
+             10,12\n3,4\n3,0\n8,4\n1,10\n2,14\n8,10\n9,0\n\n\
+             fold along y=7\nfold along x=5\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            Answer::Text("#####\n#...#\n#...#\n#...#\n#####\n.....\n.....\n".to_string()),
+            Day13::part2(&(matrix, instructions)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Day13::parse("6,10\n\nfold along y=7\n").is_ok());
+        for text in [
+            "6;10\n",
+            "6,x\n",
+            "6,10\n\nfold along z=7\n",
+            "fold along y\n",
+        ] {
+            assert!(Day13::parse(text).is_err(), "{:?}", text);
+        }
+        for (text, line, column) in [
+            ("6,x\n", 1, 3),
+            ("6,10\n\nfold along z=7\n", 3, 12),
+            ("6,10\n\nfold along y=-7\n", 3, 14),
+            ("é,10\n", 1, 1),
+            ("10,é\n", 1, 4),
+        ] {
+            assert!(
+                matches!(
+                    Day13::parse(text),
+                    Err(InputError::Parse { line: l, column: c, .. }) if (l, c) == (line, column)
+                ),
+                "{:?}",
+                text
+            );
+        }
+        assert!(matches!(
+            Day13::parse("6,10\n99999999999,99999999999\n"),
+            Err(InputError::Parse { line: 2, .. })
+        ));
+
+        let input = Day13::parse("6,10\n\nfold along x=7\n").unwrap();
+        assert!(Day13::part1(&input).is_err());
+    }
+}
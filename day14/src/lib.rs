@@ -0,0 +1,204 @@
+use itertools::Itertools;
+use std::collections::HashMap;
+use utils::{Answer, InputError, Solution, SolveError};
+
+/// Insertion rules by pair: the inserted element and the two pairs it makes.
+pub type Rules = HashMap<String, (char, String, String)>;
+
+fn parse_input(input: Vec<String>) -> Result<(String, Rules), InputError> {
+    let mut input = input.iter();
+    let seed = input.next().map(String::as_str).unwrap_or_default();
+    if seed.is_empty() {
+        return Err(InputError::parse(1, 1, "the polymer template is empty"));
+    }
+
+    if !input.next().is_some_and(|line| line.is_empty()) {
+        return Err(InputError::parse(2, 1, "expected a blank line"));
+    }
+
+    let mut instructions: Rules = HashMap::new();
+
+    for (index, instruction) in input.enumerate() {
+        let rule = instruction.split_once(" -> ").and_then(|(pair, middle)| {
+            let keys: Vec<char> = pair.chars().collect();
+            let middle: Vec<char> = middle.chars().collect();
+            match (&keys[..], &middle[..]) {
+                (&[a, b], &[m]) => Some(((a, b), m)),
+                _ => None,
+            }
+        });
+        let (key, middle) =
+            rule.ok_or_else(|| InputError::parse(index + 3, 1, "expected \"AB -> C\""))?;
+
+        let front = format!("{}{}", key.0, middle);
+        let back = format!("{}{}", middle, key.1);
+
+        instructions.insert(format!("{}{}", key.0, key.1), (middle, front, back));
+    }
+
+    Ok((seed.to_string(), instructions))
+}
+
+fn count_chars(input: &HashMap<char, u64>) -> u64 {
+    let max = input.iter().max_by_key(|entry| entry.1).unwrap();
+    let min = input.iter().min_by_key(|entry| entry.1).unwrap();
+
+    max.1 - min.1
+}
+
+fn step(seed: &str, instructions: &Rules, iterations: u32) -> u64 {
+    let mut char_counter: HashMap<char, u64> = HashMap::new();
+    let mut current_step: HashMap<String, u64> = HashMap::new();
+
+    let seed = seed.chars().collect::<Vec<char>>();
+
+    for (front, back) in seed.iter().tuple_windows() {
+        let count = char_counter.entry(*front).or_insert(0);
+        *count += 1;
+
+        *(current_step
+            .entry(format!("{}{}", *front, *back))
+            .or_insert(0)) += 1;
+    }
+
+    *(char_counter.entry(*seed.last().unwrap()).or_insert(0)) += 1;
+
+    for _i in 0..iterations {
+        let mut next_step: HashMap<String, u64> = HashMap::new();
+
+        for (pair, count) in current_step.clone() {
+            // A pair without a rule is left as it is.
+            let Some((middle, front, back)) = instructions.get(&pair) else {
+                *(next_step.entry(pair).or_insert(0)) += count;
+                continue;
+            };
+
+            *(char_counter.entry(*middle).or_insert(0)) += count;
+
+            *(next_step.entry(front.clone()).or_insert(0)) += count;
+
+            *(next_step.entry(back.clone()).or_insert(0)) += count;
+        }
+        current_step = next_step;
+    }
+
+    count_chars(&char_counter)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = (String, Rules);
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        parse_input(input.lines().map(String::from).collect())
+    }
+
+    fn part1((seed, instructions): &Self::Input) -> Result<Answer, SolveError> {
+        Ok(step(seed, instructions, 10).into())
+    }
+
+    fn part2((seed, instructions): &Self::Input) -> Result<Answer, SolveError> {
+        Ok(step(seed, instructions, 40).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use utils::gen;
+
+    #[test]
+    fn test_parse_input() {
+        let input = vec![
+            "NNCB".to_string(),
+            "".to_string(),
+            "CH -> B".to_string(),
+            "HH -> N".to_string(),
+        ];
+
+        let mut expected: HashMap<String, (char, String, String)> = HashMap::new();
+        expected.insert("CH".to_string(), ('B', "CB".to_string(), "BH".to_string()));
+        expected.insert("HH".to_string(), ('N', "HN".to_string(), "NH".to_string()));
+
+        assert_eq!(parse_input(input).unwrap(), ("NNCB".to_string(), expected))
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Day14::parse("NNCB\n\nCH -> B\n").is_ok());
+        assert!(matches!(
+            Day14::parse("NNCB\n\nCH -> B\nCHH -> B\n"),
+            Err(InputError::Parse { line: 4, .. })
+        ));
+        for text in ["", "\n\nCH -> B\n", "NNCB\nCH -> B\n", "NNCB\n\nCH => B\n"] {
+            assert!(Day14::parse(text).is_err(), "{:?}", text);
+        }
+    }
+
+    #[test]
+    fn test_missing_rule() {
+        let (seed, instructions) = Day14::parse("NNCB\n\nNN -> C\n").unwrap();
+        // NCNCB: two N, two C, one B.
+        assert_eq!(1, step(&seed, &instructions, 1));
+    }
+
+    #[test]
+    fn test_step() {
+        let input = vec![
+            "NNCB".to_string(),
+            "".to_string(),
+            "CH -> B".to_string(),
+            "HH -> N".to_string(),
+            "CB -> H".to_string(),
+            "NH -> C".to_string(),
+            "HB -> C".to_string(),
+            "HC -> B".to_string(),
+            "HN -> C".to_string(),
+            "NN -> C".to_string(),
+            "BH -> H".to_string(),
+            "NC -> B".to_string(),
+            "NB -> B".to_string(),
+            "BN -> B".to_string(),
+            "BB -> N".to_string(),
+            "BC -> B".to_string(),
+            "CC -> N".to_string(),
+            "CN -> C".to_string(),
+        ];
+
+        let (seed, instructions) = parse_input(input).unwrap();
+
+        let count = step(&seed, &instructions, 10);
+        assert_eq!(1588, count);
+        assert_eq!(1588, expand(&seed, &instructions, 10));
+    }
+
+    /// Applies the rules to the polymer itself, then counts its elements.
+    fn expand(template: &str, rules: &Rules, steps: u32) -> u64 {
+        let mut polymer: Vec<char> = template.chars().collect();
+        for _ in 0..steps {
+            let mut next = vec![polymer[0]];
+            for pair in polymer.windows(2) {
+                next.push(rules[&pair.iter().collect::<String>()].0);
+                next.push(pair[1]);
+            }
+            polymer = next;
+        }
+
+        let mut counts: HashMap<char, u64> = HashMap::new();
+        for element in polymer {
+            *counts.entry(element).or_insert(0) += 1;
+        }
+        counts.values().max().unwrap() - counts.values().min().unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn test_pair_counts_match_expansion(text in gen::polymer(), steps in 0..=10u32) {
+            let (template, rules) = Day14::parse(&text).unwrap();
+            prop_assert_eq!(expand(&template, &rules, steps), step(&template, &rules, steps));
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use utils::{Answer, Grid, InputError, Point, Solution, SolveError};
+
+extern crate pathfinding;
+
+use pathfinding::prelude::dijkstra;
+
+fn parse_input(input: &str) -> Result<Grid<u16>, InputError> {
+    let grid: Grid<u16> = input.parse()?;
+    if grid.width() == 0 {
+        return Err(InputError::parse(1, 1, "empty risk map"));
+    }
+    Ok(grid)
+}
+
+/// Lowest total risk from the top left to the bottom right corner; the
+/// starting position is never entered, so its risk is not counted.
+fn compute_part1(input: &Grid<u16>) -> u16 {
+    let goal = Point::new(input.width() - 1, input.height() - 1);
+
+    match dijkstra(&Point::new(0, 0), |&p| neighbours(p, input), |p| *p == goal) {
+        Some((_path, risk)) => risk,
+        None => 0,
+    }
+}
+
+fn neighbours(p: Point<usize>, points: &Grid<u16>) -> Vec<(Point<usize>, u16)> {
+    points
+        .neighbours4(p.x, p.y)
+        .map(|n| (Point::from(n), points[n]))
+        .collect()
+}
+
+fn expand_map(input: &Grid<u16>) -> Grid<u16> {
+    input.tile(5, 5, |weight, add_x, add_y| {
+        let mut new_weight = *weight + add_x as u16 + add_y as u16;
+
+        if new_weight > 9 {
+            new_weight -= 9;
+        }
+
+        new_weight
+    })
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = Grid<u16>;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(compute_part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(compute_part1(&expand_map(input)).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "1163751742\n\
+                           1381373672\n\
+                           2136511328\n\
+                           3694931569\n\
+                           7463417111\n\
+                           1319128137\n\
+                           1359912421\n\
+                           3125421639\n\
+                           1293138521\n\
+                           2311944581\n";
+
+    #[test]
+    fn test_parse() {
+        let input = parse_input(EXAMPLE).unwrap();
+
+        let result = compute_part1(&input);
+        assert_eq!(40, result);
+    }
+
+    #[test]
+    fn test_parse2() {
+        let input = parse_input(EXAMPLE).unwrap();
+
+        let new_map = expand_map(&input);
+        assert_eq!((50, 50), (new_map.width(), new_map.height()));
+        assert_eq!(
+            Some("11637517422274862853338597396444961841755517295286"),
+            new_map.to_string().lines().next()
+        );
+
+        let result = compute_part1(&new_map);
+        assert_eq!(315, result);
+    }
+}
@@ -0,0 +1,519 @@
+use to_binary::BinaryString;
+use utils::input::InputError;
+use utils::{Answer, Solution, SolveError};
+
+// Type ID 0 : then the next 15 bits are a number that represents the total length in bits of the sub-packets contained by this packet.
+// Type ID 1 : then the next 11 bits are a number that represents the number of sub-packets immediately contained by this packet.
+// Type ID 4 : literal value
+// Type ID 6 : operator
+
+#[derive(PartialEq, Eq, Debug, Default)]
+pub enum PacketType {
+    Literal,
+    Operator(u16),
+    #[default]
+    Err,
+}
+
+impl PacketType {
+    fn from_str(input: &str) -> Self {
+        match input {
+            "100" => Self::Literal,
+            _ => Self::Operator(u16::from_str_radix(input, 2).unwrap()),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Default)]
+pub struct Packet {
+    pub version: u32,
+    pub packet_type: PacketType,
+    pub literal_value: usize,
+    pub operations_packets: Vec<Packet>,
+}
+
+/// The `len` bits of `input` starting at `start`.
+fn bits(input: &str, start: usize, len: usize) -> Result<&str, String> {
+    input
+        .get(start..start + len)
+        .ok_or_else(|| format!("the transmission ends after {} bits", input.len()))
+}
+
+/// How deep packets may be nested in one another. Parsing recurses once per
+/// level, so this bounds the stack used by malformed transmissions.
+const MAX_NESTING: usize = 100;
+
+fn parse_packet(input: &str) -> Result<(Packet, &str), String> {
+    parse_nested(input, 0)
+}
+
+/// Parses a packet found inside `depth` operators.
+fn parse_nested(input: &str, depth: usize) -> Result<(Packet, &str), String> {
+    if depth > MAX_NESTING {
+        return Err(format!("packets are nested more than {} deep", MAX_NESTING));
+    }
+    let version = u32::from_str_radix(bits(input, 0, 3)?, 2).unwrap();
+
+    match PacketType::from_str(bits(input, 3, 3)?) {
+        PacketType::Literal => {
+            let mut start_num: usize = 6;
+            let mut binary_rep: Vec<&str> = Vec::new();
+
+            while "1" == bits(input, start_num, 1)? {
+                binary_rep.push(bits(input, start_num + 1, 4)?);
+                start_num += 5;
+            }
+
+            binary_rep.push(bits(input, start_num + 1, 4)?);
+
+            let literal_value = usize::from_str_radix(&binary_rep.join(""), 2)
+                .map_err(|_| format!("a literal of {} bits is too large", binary_rep.len() * 4))?;
+
+            Ok((
+                Packet {
+                    version,
+                    packet_type: PacketType::Literal,
+                    literal_value,
+                    operations_packets: Vec::new(),
+                },
+                &input[(start_num + 5)..],
+            ))
+        }
+
+        PacketType::Operator(op_type) => {
+            let i_size_type: usize = 6;
+
+            let (sub_packets, remainder) = match bits(input, i_size_type, 1)? {
+                "0" => {
+                    let mut sub_packets: Vec<Packet> = Vec::new();
+                    let length = usize::from_str_radix(bits(input, 7, 15)?, 2).unwrap();
+
+                    // Only the next `length` bits belong to this packet, the
+                    // rest are its siblings.
+                    let mut sub_input = bits(input, 22, length)?;
+
+                    while sub_input.len() >= 11 {
+                        let (pack, remainder) = parse_nested(sub_input, depth + 1)?;
+                        sub_packets.push(pack);
+                        sub_input = remainder;
+                    }
+
+                    (sub_packets, &input[22 + length..])
+                }
+                _ => {
+                    let num_pack = usize::from_str_radix(bits(input, 7, 11)?, 2).unwrap();
+
+                    let mut sub_packets: Vec<Packet> = Vec::new();
+
+                    let mut sub_input = &input[18.min(input.len())..];
+
+                    for _ in 0..num_pack {
+                        let (pack, remainder) = parse_nested(sub_input, depth + 1)?;
+                        sub_packets.push(pack);
+                        sub_input = remainder;
+                    }
+
+                    (sub_packets, sub_input)
+                }
+            };
+
+            // Comparisons are between exactly two packets.
+            if matches!(op_type, 5..=7) && sub_packets.len() != 2 {
+                return Err(format!(
+                    "comparison {} has {} sub-packets",
+                    op_type,
+                    sub_packets.len()
+                ));
+            }
+
+            Ok((
+                Packet {
+                    version,
+                    packet_type: PacketType::Operator(op_type),
+                    literal_value: 0,
+                    operations_packets: sub_packets,
+                },
+                remainder,
+            ))
+        }
+        _ => Ok((
+            Packet {
+                version: 0,
+                packet_type: PacketType::Err,
+                literal_value: 0,
+                operations_packets: Vec::new(),
+            },
+            input,
+        )),
+    }
+}
+
+fn compute_version_num(packet: &Packet) -> u32 {
+    match packet.packet_type {
+        PacketType::Literal => packet.version,
+        PacketType::Operator(_) => {
+            packet.version
+                + packet
+                    .operations_packets
+                    .iter()
+                    .map(compute_version_num)
+                    .sum::<u32>()
+        }
+        PacketType::Err => 0,
+    }
+}
+
+/// The value of the expression, `None` if it overflows.
+fn compute_packet(packet: &Packet) -> Option<usize> {
+    match packet.packet_type {
+        PacketType::Literal => Some(packet.literal_value),
+        PacketType::Operator(0) => packet
+            .operations_packets
+            .iter()
+            .try_fold(0usize, |acc, el| acc.checked_add(compute_packet(el)?)), //Sum
+        PacketType::Operator(1) => {
+            //let sub_packets: &Vec<Packet> = &packet.operations_packets.as_ref().unwrap();
+            packet
+                .operations_packets
+                .iter()
+                .try_fold(1usize, |acc, el| acc.checked_mul(compute_packet(el)?))
+        }
+        PacketType::Operator(2) => {
+            //let sub_packets = &mut packet.operations_packets.as_ref().unwrap().iter();
+            packet
+                .operations_packets
+                .iter()
+                .try_fold(usize::MAX, |r, p| {
+                    let res = compute_packet(p)?;
+                    if r < res {
+                        Some(r)
+                    } else {
+                        Some(res)
+                    }
+                })
+        }
+        PacketType::Operator(3) => {
+            //let sub_packets = &mut packet.operations_packets.as_ref().unwrap().iter();
+            packet.operations_packets.iter().try_fold(0, |r, p| {
+                let res = compute_packet(p)?;
+                if r > res {
+                    Some(r)
+                } else {
+                    Some(res)
+                }
+            })
+        }
+        PacketType::Operator(5) => {
+            let sub_packets = &mut packet.operations_packets.iter();
+            if compute_packet(sub_packets.next().unwrap())?
+                > compute_packet(sub_packets.next().unwrap())?
+            {
+                Some(1)
+            } else {
+                Some(0)
+            }
+        }
+        PacketType::Operator(6) => {
+            let sub_packets = &mut packet.operations_packets.iter();
+            if compute_packet(sub_packets.next().unwrap())?
+                < compute_packet(sub_packets.next().unwrap())?
+            {
+                Some(1)
+            } else {
+                Some(0)
+            }
+        }
+        PacketType::Operator(7) => {
+            let sub_packets = &mut packet.operations_packets.iter();
+            if compute_packet(sub_packets.next().unwrap())?
+                == compute_packet(sub_packets.next().unwrap())?
+            {
+                Some(1)
+            } else {
+                Some(0)
+            }
+        }
+        _ => Some(0),
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input = Packet;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        let hex = input.trim();
+
+        // BinaryString::from_hex unwraps on bad input, so check the digits first.
+        if let Some(column) = hex.find(|c: char| !c.is_ascii_hexdigit()) {
+            return Err(InputError::parse(1, column + 1, "not a hexadecimal digit"));
+        }
+        if hex.is_empty() || !hex.len().is_multiple_of(2) {
+            return Err(InputError::parse(
+                1,
+                hex.len() + 1,
+                "expected a non-empty, even number of hexadecimal digits",
+            ));
+        }
+
+        let conv = BinaryString::from_hex(hex).unwrap().to_string();
+
+        let (pack, _remainder) = parse_packet(&conv).map_err(|e| InputError::parse(1, 1, e))?;
+        Ok(pack)
+    }
+
+    fn part1(pack: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(compute_version_num(pack).into())
+    }
+
+    fn part2(pack: &Self::Input) -> Result<Answer, SolveError> {
+        compute_packet(pack)
+            .map(Answer::from)
+            .ok_or_else(|| SolveError::new("the value of the transmission overflows"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use proptest::prelude::*;
+    use utils::gen;
+
+    #[test]
+    fn test_parsing() {
+        let _input = "38006F45291200";
+
+        let conv = BinaryString::from_hex("38006F45291200")
+            .unwrap()
+            .to_string();
+
+        assert_eq!(
+            String::from("00111000000000000110111101000101001010010001001000000000"),
+            conv
+        );
+    }
+
+    #[test]
+    fn test_parse_first_exemple() {
+        let input = "110100101111111000101000";
+
+        let expected = Packet {
+            version: 6,
+            packet_type: PacketType::Literal,
+            literal_value: 2021,
+            operations_packets: Vec::new(),
+        };
+
+        assert_eq!(Ok((expected, "000")), parse_packet(input))
+    }
+
+    #[test]
+    fn test_parse_second_example() {
+        let _input = "38006F45291200";
+
+        let conv = BinaryString::from_hex("38006F45291200")
+            .unwrap()
+            .to_string();
+
+        let (_pack, _remainder) = parse_packet(&conv).unwrap();
+
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_parse_third_example() {
+        let input = "11101110000000001101010000001100100000100011000001100000";
+
+        let (_pack, _remainder) = parse_packet(input).unwrap();
+
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_sum_second_example() {
+        let conv = BinaryString::from_hex("8A004A801A8002F478")
+            .unwrap()
+            .to_string();
+
+        let (pack, _remainder) = parse_packet(&conv).unwrap();
+
+        assert_eq!(16, compute_version_num(&pack));
+    }
+
+    #[test]
+    fn test_sum_third_example() {
+        let conv = BinaryString::from_hex("620080001611562C8802118E34")
+            .unwrap()
+            .to_string();
+
+        let (pack, _remainder) = parse_packet(&conv).unwrap();
+
+        assert_eq!(12, compute_version_num(&pack));
+    }
+
+    #[test]
+    fn test_length_type_0_leaves_siblings() {
+        // An operator with two operators of length type 0, each holding two
+        // literals: the first must stop after its own bits.
+        let conv = BinaryString::from_hex("620080001611562C8802118E34")
+            .unwrap()
+            .to_string();
+
+        let (pack, _remainder) = parse_packet(&conv).unwrap();
+
+        assert_eq!(2, pack.operations_packets.len());
+        for sub_packet in &pack.operations_packets {
+            assert_eq!(2, sub_packet.operations_packets.len());
+        }
+    }
+
+    #[test]
+    fn test_sum_fourth_example() {
+        let conv = BinaryString::from_hex("A0016C880162017C3686B18A3D4780")
+            .unwrap()
+            .to_string();
+
+        let (pack, _remainder) = parse_packet(&conv).unwrap();
+
+        assert_eq!(31, compute_version_num(&pack));
+    }
+
+    #[test]
+    fn test_sum_fifth_example() {
+        let conv = BinaryString::from_hex("C0015000016115A2E0802F182340")
+            .unwrap()
+            .to_string();
+
+        let (pack, _remainder) = parse_packet(&conv).unwrap();
+
+        assert_eq!(23, compute_version_num(&pack));
+    }
+
+    #[test]
+    fn test_compute1() {
+        let conv = BinaryString::from_hex("C200B40A82").unwrap().to_string();
+        let (pack, _) = parse_packet(&conv).unwrap();
+        println!("{:?}", pack);
+        assert_eq!(Some(3), compute_packet(&pack))
+    }
+
+    #[test]
+    fn test_compute2() {
+        let conv = BinaryString::from_hex("04005AC33890").unwrap().to_string();
+        let (pack, _) = parse_packet(&conv).unwrap();
+        println!("{:?}", pack);
+        assert_eq!(Some(54), compute_packet(&pack))
+    }
+
+    #[test]
+    fn test_compute3() {
+        let conv = BinaryString::from_hex("880086C3E88112")
+            .unwrap()
+            .to_string();
+        let (pack, _) = parse_packet(&conv).unwrap();
+        println!("{:?}", pack);
+        assert_eq!(Some(7), compute_packet(&pack))
+    }
+
+    #[test]
+    fn test_compute4() {
+        let conv = BinaryString::from_hex("CE00C43D881120")
+            .unwrap()
+            .to_string();
+        let (pack, _) = parse_packet(&conv).unwrap();
+        println!("{:?}", pack);
+        assert_eq!(Some(9), compute_packet(&pack))
+    }
+
+    #[test]
+    fn test_compute5() {
+        let conv = BinaryString::from_hex("D8005AC2A8F0").unwrap().to_string();
+        let (pack, _) = parse_packet(&conv).unwrap();
+        println!("{:?}", pack);
+        assert_eq!(Some(1), compute_packet(&pack))
+    }
+
+    #[test]
+    fn test_compute6() {
+        let conv = BinaryString::from_hex("F600BC2D8F").unwrap().to_string();
+        let (pack, _) = parse_packet(&conv).unwrap();
+        println!("{:?}", pack);
+        assert_eq!(Some(0), compute_packet(&pack))
+    }
+
+    #[test]
+    fn test_compute7() {
+        let conv = BinaryString::from_hex("9C005AC2F8F0").unwrap().to_string();
+        let (pack, _) = parse_packet(&conv).unwrap();
+        println!("{:?}", pack);
+        assert_eq!(Some(0), compute_packet(&pack))
+    }
+
+    #[test]
+    fn test_compute8() {
+        let conv = BinaryString::from_hex("9C0141080250320F1802104A08")
+            .unwrap()
+            .to_string();
+        let (pack, _) = parse_packet(&conv).unwrap();
+        println!("{:?}", pack);
+        assert_eq!(Some(1), compute_packet(&pack))
+    }
+
+    #[test]
+    fn test_compute_overflow() {
+        // The product of 2^40 and 2^40.
+        let packet = Day16::parse("0600848C2108421084002461084210842000\n").unwrap();
+        assert!(Day16::part2(&packet).is_err());
+    }
+
+    #[test]
+    fn test_parse_not_hex() {
+        assert!(matches!(
+            Day16::parse("D2FE2G\n"),
+            Err(InputError::Parse { column: 6, .. })
+        ));
+        assert!(Day16::parse("D2FE2\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_malformed() {
+        // The literal of D2FE28 cut after 16 bits.
+        assert!(Day16::parse("D2FE\n").is_err());
+        // A literal of 72 bits.
+        assert!(parse_packet(&format!("000100{}00000", "11111".repeat(17))).is_err());
+        // A greater-than with a single sub-packet.
+        assert!(parse_packet("000101100000000001000100000010000").is_err());
+        assert!(parse_packet("").is_err());
+    }
+
+    #[test]
+    fn test_parse_deep_nesting() {
+        // Sums of a single sub-packet, counted by number, around a literal 1.
+        let nested = |depth: usize| format!("{}00010000001", "000000100000000001".repeat(depth));
+
+        let (mut packet, _) = parse_packet(&nested(MAX_NESTING)).unwrap();
+        for _ in 0..MAX_NESTING {
+            packet = packet.operations_packets.pop().unwrap();
+        }
+        assert_eq!(PacketType::Literal, packet.packet_type);
+
+        assert!(parse_packet(&nested(MAX_NESTING + 1)).is_err());
+        // Deep enough to overflow the stack without the limit.
+        assert!(parse_packet(&nested(1_000_000)).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn test_generated_transmissions(transmission in gen::bits_transmission()) {
+            let packet = Day16::parse(&transmission.hex).unwrap();
+            prop_assert_eq!(Ok(Answer::from(transmission.version_sum)), Day16::part1(&packet));
+            match transmission.value {
+                Some(value) => prop_assert_eq!(Ok(Answer::from(value)), Day16::part2(&packet)),
+                None => prop_assert!(Day16::part2(&packet).is_err()),
+            }
+        }
+    }
+}
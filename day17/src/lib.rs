@@ -0,0 +1,287 @@
+use std::ops::RangeInclusive;
+use utils::input::InputError;
+use utils::{Answer, Point, Rect, Solution, SolveError, Vec2};
+
+/// Moves the probe once; drag slows it down horizontally and gravity pulls
+/// it down.
+fn step(position: Point<i64>, velocity: Vec2<i64>) -> (Point<i64>, Vec2<i64>) {
+    let new_velocity = Vec2::new(velocity.x - velocity.x.signum(), velocity.y - 1);
+
+    (position + velocity, new_velocity)
+}
+
+/// The probe went past the target: too far to the right, or under it.
+fn is_further(position: Point<i64>, target: &Rect<i64>) -> bool {
+    position.x > target.max.x || position.y < target.min.y
+}
+
+/// Returns the highest point reached by a probe fired at `velocity`, if it
+/// ever lands in the target.
+fn shoot_probe(velocity: Vec2<i64>, target: &Rect<i64>) -> Option<i64> {
+    let mut position = Point::new(0, 0);
+    let mut velocity = velocity;
+
+    let mut apex_y: i64 = 0;
+
+    while !is_further(position, target) {
+        (position, velocity) = step(position, velocity);
+        if position.y > apex_y {
+            apex_y = position.y
+        }
+
+        if target.contains(position) {
+            return Some(apex_y);
+        }
+    }
+
+    None
+}
+
+/// Fires every candidate velocity; returns the highest apex reached and the
+/// number of velocities that hit the target.
+fn fire_all(target: &Rect<i64>) -> (i64, i64) {
+    let mut apex: i64 = 0;
+
+    let mut start_ok = 0;
+
+    // Any faster to the right overshoots on the first step. Upwards, a probe
+    // comes back to y = 0 at its launch speed plus one, so it cannot be
+    // faster than the depth of the target; downwards, it would be under the
+    // target after the first step.
+    let max_y = target.min.y.abs().max(target.max.y.abs());
+    for y in target.min.y.min(0)..=max_y {
+        for x in 0..=target.max.x {
+            if let Some(new_apex) = shoot_probe(Vec2::new(x, y), target) {
+                if new_apex > apex {
+                    apex = new_apex
+                }
+                start_ok += 1;
+            }
+        }
+    }
+
+    (apex, start_ok)
+}
+
+/// Farthest a target edge may be from the launch point. Every velocity up
+/// to that distance is fired; puzzle targets are a few hundred away.
+const MAX_DISTANCE: i64 = 1000;
+
+/// Reads `target area: x=20..30, y=-10..-5`, a target to the right of and
+/// below the launch point.
+fn parse_target(input: &str) -> Result<Rect<i64>, InputError> {
+    const PREFIX: &str = "target area: ";
+
+    let line = input.trim();
+    let ranges = line
+        .strip_prefix(PREFIX)
+        .ok_or_else(|| InputError::parse(1, 1, format!("expected {:?}", PREFIX)))?;
+    let (x, y) = ranges.split_once(", ").ok_or_else(|| {
+        InputError::parse(1, PREFIX.len() + 1, "expected \"x=..\" and \"y=..\" ranges")
+    })?;
+
+    let (min_x, max_x) = parse_range(x, "x=", PREFIX.len() + 1, 1..=MAX_DISTANCE)?;
+    let y_column = PREFIX.len() + x.len() + 3;
+    let (min_y, max_y) = parse_range(y, "y=", y_column, -MAX_DISTANCE..=-1)?;
+
+    Ok(Rect::new(
+        Point::new(min_x, min_y),
+        Point::new(max_x, max_y),
+    ))
+}
+
+/// Reads `x=20..30`, both bounds within `allowed`; `column` is where the
+/// field starts on the line.
+fn parse_range(
+    field: &str,
+    axis: &str,
+    column: usize,
+    allowed: RangeInclusive<i64>,
+) -> Result<(i64, i64), InputError> {
+    let bounds = field
+        .strip_prefix(axis)
+        .ok_or_else(|| InputError::parse(1, column, format!("expected {:?}", axis)))?;
+    let (min, max) = bounds
+        .split_once("..")
+        .ok_or_else(|| InputError::parse(1, column + axis.len(), "expected a range like 20..30"))?;
+
+    let min_column = column + axis.len();
+    let max_column = min_column + min.len() + 2;
+    let min = min
+        .parse::<i64>()
+        .map_err(|e| InputError::parse(1, min_column, e))?;
+    let max = max
+        .parse::<i64>()
+        .map_err(|e| InputError::parse(1, max_column, e))?;
+
+    for (bound, column) in [(min, min_column), (max, max_column)] {
+        if !allowed.contains(&bound) {
+            return Err(InputError::parse(
+                1,
+                column,
+                format!(
+                    "{}{} is out of range {}..={}",
+                    axis,
+                    bound,
+                    allowed.start(),
+                    allowed.end()
+                ),
+            ));
+        }
+    }
+
+    if min > max {
+        return Err(InputError::parse(
+            1,
+            min_column,
+            format!("empty range {}..{}", min, max),
+        ));
+    }
+
+    Ok((min, max))
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Input = Rect<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        parse_target(input)
+    }
+
+    fn part1(target: &Self::Input) -> Result<Answer, SolveError> {
+        let (apex, _) = fire_all(target);
+        Ok(apex.into())
+    }
+
+    fn part2(target: &Self::Input) -> Result<Answer, SolveError> {
+        let (_, start_ok) = fire_all(target);
+        Ok(start_ok.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use proptest::prelude::*;
+    use utils::gen;
+
+    const EXAMPLE: Rect<i64> = Rect {
+        min: Point::new(20, -10),
+        max: Point::new(30, -5),
+    };
+
+    #[test]
+    fn test_exemple1() {
+        let res = shoot_probe(Vec2::new(6, 9), &EXAMPLE).unwrap_or(0);
+
+        assert_eq!(45, res);
+    }
+
+    #[test]
+    fn test_exemple2() {
+        let res = shoot_probe(Vec2::new(9, 0), &EXAMPLE).unwrap_or(1);
+
+        assert_eq!(0, res);
+    }
+
+    #[test]
+    fn test_not_working() {
+        assert_eq!(None, shoot_probe(Vec2::new(17, -4), &EXAMPLE))
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let res = fire_all(&EXAMPLE);
+        assert_eq!((45, 112), res);
+    }
+    #[test]
+    fn test_is_further() {
+        assert!(!is_further(Point::new(20, -10), &EXAMPLE))
+    }
+
+    #[test]
+    fn test_parse_target() {
+        assert_eq!(
+            EXAMPLE,
+            parse_target("target area: x=20..30, y=-10..-5\n").unwrap()
+        );
+
+        match parse_target("target area: x=20..30, y=-1x..-5") {
+            Err(InputError::Parse { line, column, .. }) => assert_eq!((1, 26), (line, column)),
+            other => panic!("unexpected {:?}", other),
+        }
+
+        assert!(parse_target("x=20..30, y=-10..-5").is_err());
+        assert!(parse_target("target area: x=30..20, y=-10..-5").is_err());
+
+        for text in [
+            "target area: x=1..2, y=-2147483648..-1",
+            "target area: x=1..2, y=-2000000000..-1",
+            "target area: x=20..30, y=5..10",
+            "target area: x=20..30, y=-10..0",
+            "target area: x=-30..-20, y=-10..-5",
+            "target area: x=0..20, y=-10..-5",
+            "target area: x=20..3000, y=-10..-5",
+        ] {
+            assert!(parse_target(text).is_err(), "{:?}", text);
+        }
+        match parse_target("target area: x=20..30, y=-10..5") {
+            Err(InputError::Parse { column, .. }) => assert_eq!(31, column),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    /// Fires every velocity of a box far larger than needed, for a fixed
+    /// number of steps, without any early exit.
+    fn brute_force(target: &Rect<i64>) -> (i64, i64) {
+        let depth = target.min.y.abs();
+        let steps = 6 * depth + target.max.x + 10;
+
+        let mut best = 0;
+        let mut hits = 0;
+        for vx in 0..=target.max.x + 5 {
+            for vy in -2 * depth..=2 * depth {
+                let (mut position, mut velocity) = (Point::new(0, 0), Vec2::new(vx, vy));
+                let mut apex = 0;
+                let mut hit = false;
+                for _ in 0..steps {
+                    (position, velocity) = step(position, velocity);
+                    apex = apex.max(position.y);
+                    hit |= target.contains(position);
+                }
+                if hit {
+                    best = best.max(apex);
+                    hits += 1;
+                }
+            }
+        }
+        (best, hits)
+    }
+
+    #[test]
+    fn test_brute_force_example() {
+        assert_eq!((45, 112), brute_force(&EXAMPLE));
+    }
+
+    #[test]
+    fn test_steep_shot() {
+        // Reached only by firing straight down at (1, -3), which the old
+        // search box built from the x range did not try.
+        let target = parse_target("target area: x=1..1, y=-3..-3").unwrap();
+        assert_eq!((3, 4), fire_all(&target));
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn test_bounds_match_brute_force(text in gen::target_area()) {
+            let target = Day17::parse(&text).unwrap();
+            prop_assert_eq!(brute_force(&target), fire_all(&target));
+        }
+    }
+}
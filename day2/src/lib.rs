@@ -0,0 +1,562 @@
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub, ToPrimitive};
+use std::fmt::{self, Display, Write};
+use std::str::FromStr;
+use utils::input::InputError;
+use utils::{Answer, Solution, SolveError};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input = Vec<Command>;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        parse_course(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        product(&navigate::<Direct>(input)?)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        product(&navigate::<Aimed>(input)?)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Forward(i32),
+    Down(i32),
+    Up(i32),
+}
+
+impl Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Command::Forward(value) => write!(f, "forward {}", value),
+            Command::Down(value) => write!(f, "down {}", value),
+            Command::Up(value) => write!(f, "up {}", value),
+        }
+    }
+}
+
+/// Why a command could not be read, and at which column of its line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandError {
+    pub column: usize,
+    pub message: String,
+}
+
+impl Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Parses `forward 5`, `down 3` or `up 2`.
+impl FromStr for Command {
+    type Err = CommandError;
+
+    fn from_str(line: &str) -> Result<Command, CommandError> {
+        let error = |column: usize, message: String| CommandError { column, message };
+
+        // Every whitespace separated field, with the column it starts at;
+        // each separator is a single character.
+        let mut fields = Vec::new();
+        let mut column = 1;
+        for field in line.split(char::is_whitespace) {
+            if !field.is_empty() {
+                fields.push((column, field));
+            }
+            column += field.chars().count() + 1;
+        }
+
+        let ((direction_column, direction), (value_column, value)) = match fields[..] {
+            [direction, value] => (direction, value),
+            _ => {
+                return Err(error(
+                    1,
+                    format!("expected a command like \"forward 5\", found {:?}", line),
+                ))
+            }
+        };
+
+        let command: fn(i32) -> Command = match direction {
+            "forward" => Command::Forward,
+            "down" => Command::Down,
+            "up" => Command::Up,
+            _ => {
+                return Err(error(
+                    direction_column,
+                    format!("unknown command {:?}", direction),
+                ))
+            }
+        };
+        let value = value
+            .parse::<i32>()
+            .map_err(|e| error(value_column, e.to_string()))?;
+
+        Ok(command(value))
+    }
+}
+
+/// Reads one command per line, skipping blank lines.
+fn parse_course(input: &str) -> Result<Vec<Command>, InputError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            line.parse()
+                .map_err(|e: CommandError| InputError::parse(index + 1, e.column, e))
+        })
+        .collect()
+}
+
+/// The numbers a submarine counts with: `i32`, `i64`, `i128`, or a big
+/// integer such as `num_bigint::BigInt`.
+pub trait Number:
+    Clone
+    + Default
+    + Display
+    + PartialOrd
+    + From<i32>
+    + CheckedAdd
+    + CheckedSub
+    + CheckedMul
+    + ToPrimitive
+{
+}
+
+impl<N> Number for N where
+    N: Clone
+        + Default
+        + Display
+        + PartialOrd
+        + From<i32>
+        + CheckedAdd
+        + CheckedSub
+        + CheckedMul
+        + ToPrimitive
+{
+}
+
+/// How a submarine moves in response to commands.
+pub trait Submarine {
+    type Number: Number;
+
+    /// Moves the submarine, or returns `None` and leaves it where it was
+    /// when the new position does not fit in its numbers.
+    fn apply(&mut self, command: Command) -> Option<()>;
+    fn horizontal(&self) -> Self::Number;
+    fn depth(&self) -> Self::Number;
+
+    /// 0 for a submarine that does not aim.
+    fn aim(&self) -> Self::Number {
+        Self::Number::default()
+    }
+}
+
+/// Part 1: `down` and `up` change the depth directly.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Direct<N = i64> {
+    horizontal: N,
+    depth: N,
+}
+
+impl<N: Number> Submarine for Direct<N> {
+    type Number = N;
+
+    fn apply(&mut self, command: Command) -> Option<()> {
+        match command {
+            Command::Forward(value) => {
+                self.horizontal = self.horizontal.checked_add(&value.into())?
+            }
+            Command::Down(value) => self.depth = self.depth.checked_add(&value.into())?,
+            Command::Up(value) => self.depth = self.depth.checked_sub(&value.into())?,
+        }
+        Some(())
+    }
+
+    fn horizontal(&self) -> N {
+        self.horizontal.clone()
+    }
+
+    fn depth(&self) -> N {
+        self.depth.clone()
+    }
+}
+
+/// Part 2: `down` and `up` change the aim, and moving forward dives along it.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Aimed<N = i64> {
+    horizontal: N,
+    depth: N,
+    aim: N,
+}
+
+impl<N: Number> Submarine for Aimed<N> {
+    type Number = N;
+
+    fn apply(&mut self, command: Command) -> Option<()> {
+        match command {
+            Command::Forward(value) => {
+                let value = N::from(value);
+                let horizontal = self.horizontal.checked_add(&value)?;
+                self.depth = self.depth.checked_add(&self.aim.checked_mul(&value)?)?;
+                self.horizontal = horizontal;
+            }
+            Command::Down(value) => self.aim = self.aim.checked_add(&value.into())?,
+            Command::Up(value) => self.aim = self.aim.checked_sub(&value.into())?,
+        }
+        Some(())
+    }
+
+    fn horizontal(&self) -> N {
+        self.horizontal.clone()
+    }
+
+    fn depth(&self) -> N {
+        self.depth.clone()
+    }
+
+    fn aim(&self) -> N {
+        self.aim.clone()
+    }
+}
+
+/// A command that moved the submarine out of the range of its numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow {
+    /// Index of the command in the course.
+    pub index: usize,
+    pub command: Command,
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "command {} ({}) overflows", self.index + 1, self.command)
+    }
+}
+
+impl From<Overflow> for SolveError {
+    fn from(overflow: Overflow) -> Self {
+        SolveError::new(overflow.to_string())
+    }
+}
+
+/// Runs the whole course on a submarine starting at the surface.
+pub fn navigate<S: Submarine + Default>(course: &[Command]) -> Result<S, Overflow> {
+    let mut submarine = S::default();
+    for (index, &command) in course.iter().enumerate() {
+        submarine
+            .apply(command)
+            .ok_or(Overflow { index, command })?;
+    }
+    Ok(submarine)
+}
+
+/// The product of the final horizontal position and depth.
+fn product<S: Submarine>(submarine: &S) -> Result<Answer, SolveError> {
+    let product = submarine
+        .horizontal()
+        .checked_mul(&submarine.depth())
+        .ok_or_else(|| SolveError::new("the product of the position overflows"))?;
+    Ok(match product.to_i128() {
+        Some(value) => Answer::Int(value),
+        None => Answer::Text(product.to_string()),
+    })
+}
+
+/// Where a submarine is after a command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step<N = i64> {
+    pub command: Command,
+    pub horizontal: N,
+    pub depth: N,
+    pub aim: N,
+}
+
+/// Every position of a submarine along a course, one step per command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trajectory<N = i64> {
+    pub steps: Vec<Step<N>>,
+}
+
+impl<N> Default for Trajectory<N> {
+    fn default() -> Self {
+        Trajectory { steps: Vec::new() }
+    }
+}
+
+/// Runs the course like [`navigate`], recording the position after each
+/// command.
+pub fn trace<S: Submarine + Default>(
+    course: &[Command],
+) -> Result<Trajectory<S::Number>, Overflow> {
+    let mut submarine = S::default();
+    let mut steps = Vec::with_capacity(course.len());
+    for (index, &command) in course.iter().enumerate() {
+        submarine
+            .apply(command)
+            .ok_or(Overflow { index, command })?;
+        steps.push(Step {
+            command,
+            horizontal: submarine.horizontal(),
+            depth: submarine.depth(),
+            aim: submarine.aim(),
+        });
+    }
+    Ok(Trajectory { steps })
+}
+
+impl<N: Number> Trajectory<N> {
+    /// Index of the first command after which the depth is more than `depth`.
+    pub fn first_deeper_than(&self, depth: N) -> Option<usize> {
+        self.steps.iter().position(|step| step.depth > depth)
+    }
+
+    /// One line per command, numbered from 1:
+    /// `step,command,horizontal,depth,aim`.
+    pub fn csv(&self) -> String {
+        let mut output = String::from("step,command,horizontal,depth,aim\n");
+        for (index, step) in self.steps.iter().enumerate() {
+            let _ = writeln!(
+                output,
+                "{},{},{},{},{}",
+                index + 1,
+                step.command,
+                step.horizontal,
+                step.depth,
+                step.aim
+            );
+        }
+        output
+    }
+
+    /// A side view of at most `width` x `height` characters, from the start
+    /// at the surface: horizontal distance to the right, depth downwards.
+    /// The surface is drawn with `~` and the course with `*`.
+    pub fn plot(&self, width: usize, height: usize) -> String {
+        let float = |value: &N| value.to_f64().unwrap_or(f64::NAN);
+        let points: Vec<(f64, f64)> = std::iter::once((0.0, 0.0))
+            .chain(
+                self.steps
+                    .iter()
+                    .map(|step| (float(&step.horizontal), float(&step.depth))),
+            )
+            .collect();
+        if width == 0 || height == 0 {
+            return String::new();
+        }
+
+        let range = |values: Vec<f64>| {
+            let min = values.iter().copied().fold(0.0, f64::min);
+            let max = values.iter().copied().fold(0.0, f64::max);
+            (min, max)
+        };
+        let (x_min, x_max) = range(points.iter().map(|p| p.0).collect());
+        let (y_min, y_max) = range(points.iter().map(|p| p.1).collect());
+        let scale = |value: f64, min: f64, max: f64, cells: usize| {
+            if max == min || value.is_nan() {
+                0
+            } else {
+                // The small offset keeps the maximum in the last cell despite
+                // rounding.
+                let cell = ((value - min) * (cells - 1) as f64 / (max - min) + 1e-9).floor();
+                (cell as usize).min(cells - 1)
+            }
+        };
+        let cell = |(x, y): (f64, f64)| {
+            (
+                scale(x, x_min, x_max, width),
+                scale(y, y_min, y_max, height),
+            )
+        };
+
+        let mut rows = vec![vec![' '; width]; height];
+        rows[cell((0.0, 0.0)).1] = vec!['~'; width];
+        for pair in points.windows(2) {
+            let (from, to) = (cell(pair[0]), cell(pair[1]));
+            let steps = from.0.abs_diff(to.0).max(from.1.abs_diff(to.1)).max(1);
+            for n in 0..=steps {
+                let between = |a: usize, b: usize| {
+                    (a as i64 + (b as i64 - a as i64) * n as i64 / steps as i64) as usize
+                };
+                rows[between(from.1, to.1)][between(from.0, to.0)] = '*';
+            }
+        }
+
+        let mut output = String::new();
+        for row in rows {
+            let line: String = row.into_iter().collect();
+            let _ = writeln!(output, "{}", line.trim_end());
+        }
+        let _ = writeln!(
+            output,
+            "horizontal {}..{}, depth {}..{}",
+            x_min, x_max, y_min, y_max
+        );
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigInt;
+
+    const EXAMPLE: &str = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n";
+
+    #[test]
+    fn test_example() {
+        let course = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(Ok(Answer::Int(150)), Day2::part1(&course));
+        assert_eq!(Ok(Answer::Int(900)), Day2::part2(&course));
+    }
+
+    #[test]
+    fn test_submarines() {
+        let course = [
+            Command::Forward(5),
+            Command::Down(5),
+            Command::Forward(8),
+            Command::Up(2),
+        ];
+        let direct: Direct = navigate(&course).unwrap();
+        assert_eq!((13, 3), (direct.horizontal(), direct.depth()));
+        let aimed: Aimed = navigate(&course).unwrap();
+        assert_eq!((13, 40, 3), (aimed.horizontal(), aimed.depth(), aimed.aim));
+
+        assert_eq!(Ok(Command::Up(3)), "up 3".parse());
+    }
+
+    #[test]
+    fn test_trace() {
+        let course = Day2::parse(EXAMPLE).unwrap();
+        let trajectory = trace::<Aimed>(&course).unwrap();
+        assert_eq!(6, trajectory.steps.len());
+        assert_eq!(
+            Step {
+                command: Command::Forward(2),
+                horizontal: 15,
+                depth: 60,
+                aim: 10,
+            },
+            trajectory.steps[5]
+        );
+        assert_eq!(Some(2), trajectory.first_deeper_than(0));
+        assert_eq!(Some(5), trajectory.first_deeper_than(40));
+        assert_eq!(None, trajectory.first_deeper_than(60));
+
+        let csv = trajectory.csv();
+        assert_eq!(Some("3,forward 8,13,40,5"), csv.lines().nth(3), "{}", csv);
+        assert_eq!(0, trace::<Direct>(&course).unwrap().steps[1].aim);
+    }
+
+    #[test]
+    fn test_plot() {
+        let course = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            "****~~~~\n\
+             \x20   **\n\
+             \x20     *\n\
+             \x20      *\n\
+            horizontal 0..15, depth 0..60\n",
+            trace::<Aimed>(&course).unwrap().plot(8, 4)
+        );
+        assert_eq!(
+            "~~~\nhorizontal 0..0, depth 0..0\n",
+            Trajectory::<i64>::default().plot(3, 1)
+        );
+    }
+
+    /// Aims down by 100000 then moves forward by 1000, `n` times, so the
+    /// final depth is 100000 * 1000 * (1 + 2 + ... + n).
+    fn dives(n: usize) -> Vec<Command> {
+        [Command::Down(100_000), Command::Forward(1000)].repeat(n)
+    }
+
+    #[test]
+    fn test_overflow() {
+        // The depth reaches 21e8 after 6 dives, and 28e8 > i32::MAX after 7.
+        let course = dives(10);
+        let overflow = Overflow {
+            index: 13,
+            command: Command::Forward(1000),
+        };
+        assert_eq!(Err(overflow), navigate::<Aimed<i32>>(&course).map(|_| ()));
+        assert_eq!(Err(overflow), trace::<Aimed<i32>>(&course).map(|_| ()));
+        assert_eq!("command 14 (forward 1000) overflows", overflow.to_string());
+        assert!(navigate::<Aimed<i32>>(&course[..12]).is_ok());
+        assert!(navigate::<Aimed>(&course).is_ok());
+
+        let course = [Command::Up(i32::MAX), Command::Up(2)];
+        assert!(navigate::<Direct<i32>>(&course).is_err());
+        assert_eq!(
+            Ok(-(i32::MAX as i64) - 2),
+            navigate::<Direct>(&course).map(|s| s.depth())
+        );
+    }
+
+    #[test]
+    fn test_long_course() {
+        let n: i128 = 1_000_000;
+        let course = dives(n as usize);
+        let depth = 100_000_000 * n * (n + 1) / 2;
+
+        let submarine = navigate::<Aimed<i128>>(&course).unwrap();
+        assert_eq!(depth, submarine.depth());
+        assert!(matches!(
+            navigate::<Aimed>(&course),
+            Err(Overflow {
+                command: Command::Forward(1000),
+                ..
+            })
+        ));
+        assert!(Day2::part2(&course).is_err());
+
+        let submarine = navigate::<Aimed<BigInt>>(&course).unwrap();
+        assert_eq!(BigInt::from(depth), submarine.depth());
+        assert_eq!(Ok(Answer::Int(depth * 1_000_000_000)), product(&submarine));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(
+            Day2::parse("forward 5\nbackward 2\n"),
+            Err(InputError::Parse {
+                line: 2,
+                column: 1,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Day2::parse("up x"),
+            Err(InputError::Parse {
+                line: 1,
+                column: 4,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Day2::parse("\n\nforward 1.5"),
+            Err(InputError::Parse {
+                line: 3,
+                column: 9,
+                ..
+            })
+        ));
+        assert!(Day2::parse("down").is_err());
+        assert!(Day2::parse("down 1 2").is_err());
+
+        // The value is found after the direction, even when it is a
+        // substring of it.
+        let column = |line: &str| line.parse::<Command>().unwrap_err().column;
+        assert_eq!(4, column("up p"));
+        assert_eq!(9, column("forward f"));
+        assert_eq!(3, column("  sideways 1"));
+        // Columns count characters, here an ideographic space.
+        assert_eq!(5, column("\u{3000}up x"));
+    }
+}
@@ -0,0 +1,325 @@
+use utils::input::InputError;
+use utils::{Answer, Solution, SolveError};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Input = Report;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        parse_report(input)
+    }
+
+    fn part1(report: &Self::Input) -> Result<Answer, SolveError> {
+        let (gamma, epsilon) = power_rates(report);
+        Ok((i128::from(gamma) * i128::from(epsilon)).into())
+    }
+
+    fn part2(report: &Self::Input) -> Result<Answer, SolveError> {
+        let mut sorted = report.values.clone();
+        sorted.sort_unstable();
+
+        let oxygen_rate = rating(report, &sorted, BitCriteria::OXYGEN)?;
+        let co2_rate = rating(report, &sorted, BitCriteria::CO2)?;
+
+        Ok((i128::from(oxygen_rate) * i128::from(co2_rate)).into())
+    }
+}
+
+/// The diagnostic report: numbers of `width` bits, the first column of the
+/// input being the most significant bit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub width: u32,
+    pub values: Vec<u64>,
+}
+
+impl Report {
+    /// The bit of `value` in the given column, counted from the left.
+    fn bit(&self, value: u64, column: u32) -> bool {
+        value >> (self.width - 1 - column) & 1 == 1
+    }
+
+    fn mask(&self) -> u64 {
+        u64::MAX >> (64 - self.width)
+    }
+}
+
+/// Reads the diagnostic report: one binary number per line, all of the same
+/// width of at most 64 bits. Blank lines are skipped.
+fn parse_report(input: &str) -> Result<Report, InputError> {
+    let lines: Vec<(usize, &str)> = input
+        .lines()
+        .map(|line| line.trim_end_matches('\r'))
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .collect();
+    if lines.is_empty() {
+        return Err(InputError::parse(1, 1, "the report is empty"));
+    }
+    let width = lines[0].1.len();
+
+    let mut values = Vec::with_capacity(lines.len());
+    for &(index, line) in &lines {
+        if let Some(column) = line.find(|c| c != '0' && c != '1') {
+            return Err(InputError::parse(
+                index + 1,
+                column + 1,
+                "not a binary digit",
+            ));
+        }
+        if line.len() != width {
+            return Err(InputError::parse(
+                index + 1,
+                1,
+                format!("expected {} bits, found {}", width, line.len()),
+            ));
+        }
+        if width > 64 {
+            return Err(InputError::parse(
+                index + 1,
+                65,
+                format!("numbers of {} bits are wider than 64 bits", width),
+            ));
+        }
+        values.push(u64::from_str_radix(line, 2).unwrap_or_default());
+    }
+
+    Ok(Report {
+        width: width as u32,
+        values,
+    })
+}
+
+/// The bit a rating keeps in each column, given how many of the remaining
+/// numbers have a one and a zero there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitCriteria {
+    /// The most common bit, or `tie` when there are as many of each.
+    MostCommon { tie: bool },
+    /// The least common bit, or `tie` when there are as many of each.
+    LeastCommon { tie: bool },
+}
+
+impl BitCriteria {
+    pub const OXYGEN: BitCriteria = BitCriteria::MostCommon { tie: true };
+    pub const CO2: BitCriteria = BitCriteria::LeastCommon { tie: false };
+
+    pub fn select(self, ones: usize, zeros: usize) -> bool {
+        match self {
+            BitCriteria::MostCommon { tie } if ones == zeros => tie,
+            BitCriteria::LeastCommon { tie } if ones == zeros => tie,
+            BitCriteria::MostCommon { .. } => ones > zeros,
+            BitCriteria::LeastCommon { .. } => ones < zeros,
+        }
+    }
+}
+
+/// Gamma has the most common bit of each column, and epsilon the least common.
+fn power_rates(report: &Report) -> (u64, u64) {
+    let most_common = BitCriteria::MostCommon { tie: true };
+    let gamma = (0..report.width)
+        .filter(|&column| {
+            let (ones, zeros) = count_bits(report, &report.values, column);
+            most_common.select(ones, zeros)
+        })
+        .fold(0, |gamma, column| gamma | 1 << (report.width - 1 - column));
+    (gamma, !gamma & report.mask())
+}
+
+/// How many of `values` have a one, and a zero, in `column`.
+fn count_bits(report: &Report, values: &[u64], column: u32) -> (usize, usize) {
+    let ones = values
+        .iter()
+        .filter(|&&value| report.bit(value, column))
+        .count();
+    (ones, values.len() - ones)
+}
+
+/// Keeps the numbers whose bit matches `criteria`, one column at a time from
+/// the left, until a single number is left.
+///
+/// `sorted` are the numbers of the report in increasing order, so that the
+/// numbers left always form a range, with those having a zero in the next
+/// column before those having a one.
+fn rating(report: &Report, sorted: &[u64], criteria: BitCriteria) -> Result<u64, SolveError> {
+    let (mut start, mut end) = (0, sorted.len());
+
+    for column in 0..report.width {
+        if end - start <= 1 {
+            break;
+        }
+        let split = start + sorted[start..end].partition_point(|&value| !report.bit(value, column));
+        if criteria.select(end - split, split - start) {
+            start = split;
+        } else {
+            end = split;
+        }
+    }
+
+    // Numbers left after the last column are all equal.
+    if start == end {
+        return Err(SolveError::new(format!(
+            "no number is left with the criteria {:?}",
+            criteria
+        )));
+    }
+    Ok(sorted[start])
+}
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+
+    const EXAMPLE: &str =
+        "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010\n";
+
+    #[test]
+    fn test_bit_criteria() {
+        for ones in 0..6 {
+            for zeros in 0..6 {
+                for tie in [false, true] {
+                    let most = BitCriteria::MostCommon { tie }.select(ones, zeros);
+                    let least = BitCriteria::LeastCommon { tie }.select(ones, zeros);
+                    if ones == zeros {
+                        assert_eq!((tie, tie), (most, least));
+                    } else {
+                        assert_eq!(ones > zeros, most);
+                        assert_eq!(most, !least);
+                    }
+                }
+            }
+        }
+
+        // An odd count: 2 ones out of 5 is not a majority.
+        assert!(!BitCriteria::OXYGEN.select(2, 3));
+        assert!(BitCriteria::OXYGEN.select(1, 1));
+        assert!(!BitCriteria::CO2.select(1, 1));
+    }
+
+    /// The ratings by filtering the numbers column by column.
+    fn filter_rating(report: &Report, criteria: BitCriteria) -> Option<u64> {
+        let mut values = report.values.clone();
+        for column in 0..report.width {
+            if values.len() <= 1 {
+                break;
+            }
+            let (ones, zeros) = count_bits(report, &values, column);
+            let keep = criteria.select(ones, zeros);
+            values.retain(|&value| report.bit(value, column) == keep);
+        }
+        values.first().copied()
+    }
+
+    fn sorted(report: &Report) -> Vec<u64> {
+        let mut sorted = report.values.clone();
+        sorted.sort_unstable();
+        sorted
+    }
+
+    #[test]
+    fn test_rating() {
+        let report = parse_report(EXAMPLE).unwrap();
+        assert_eq!(
+            Ok(23),
+            rating(&report, &sorted(&report), BitCriteria::OXYGEN)
+        );
+        assert_eq!(Ok(10), rating(&report, &sorted(&report), BitCriteria::CO2));
+
+        // Duplicates are never split.
+        let report = parse_report("01\n01\n").unwrap();
+        assert_eq!(
+            Ok(1),
+            rating(&report, &sorted(&report), BitCriteria::OXYGEN)
+        );
+        // A least common bit that no number has empties the filter.
+        assert!(rating(&report, &sorted(&report), BitCriteria::CO2).is_err());
+        assert!(Day3::part2(&report).is_err());
+    }
+
+    #[test]
+    fn test_rating_matches_filtering() {
+        let criteria = [
+            BitCriteria::OXYGEN,
+            BitCriteria::CO2,
+            BitCriteria::MostCommon { tie: false },
+            BitCriteria::LeastCommon { tie: true },
+        ];
+        // Every report of 1 to 5 numbers of 3 bits, duplicates included.
+        for count in 1..=5u32 {
+            for seed in 0..8u64.pow(count) {
+                let values: Vec<u64> = (0..count).map(|n| seed >> (3 * n) & 0b111).collect();
+                let report = Report { width: 3, values };
+                let sorted = sorted(&report);
+                for criteria in criteria {
+                    assert_eq!(
+                        filter_rating(&report, criteria),
+                        rating(&report, &sorted, criteria).ok(),
+                        "{:?} {:?}",
+                        report.values,
+                        criteria
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_example() {
+        let report = Day3::parse(EXAMPLE).unwrap();
+        assert_eq!(5, report.width);
+        assert_eq!((22, 9), power_rates(&report));
+        assert_eq!(Ok(Answer::Int(198)), Day3::part1(&report));
+        assert_eq!(Ok(Answer::Int(230)), Day3::part2(&report));
+    }
+
+    #[test]
+    fn test_wide_report() {
+        let high = format!("1{}", "0".repeat(63));
+        let report = Day3::parse(&format!("{}\n{}\n", high, high)).unwrap();
+        assert_eq!(vec![1 << 63, 1 << 63], report.values);
+        assert_eq!((1 << 63, u64::MAX >> 1), power_rates(&report));
+
+        assert!(matches!(
+            Day3::parse(&"1".repeat(65)),
+            Err(InputError::Parse { column: 65, .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_report() {
+        assert_eq!(
+            Report {
+                width: 5,
+                values: vec![0b00100, 0b11110],
+            },
+            parse_report("00100\n11110\n").unwrap()
+        );
+        assert!(matches!(
+            parse_report("00100\n11210\n"),
+            Err(InputError::Parse {
+                line: 2,
+                column: 3,
+                ..
+            })
+        ));
+        assert!(parse_report("00100\n1111\n").is_err());
+        assert!(parse_report("\n").is_err());
+        assert!(parse_report("").is_err());
+    }
+
+    #[test]
+    fn test_parse_crlf_and_blank_lines() {
+        let report = parse_report("\r\n00100\r\n11110\r\n\r\n").unwrap();
+        assert_eq!(vec![0b00100, 0b11110], report.values);
+        assert_eq!(report, parse_report("00100\n\n11110\n\n").unwrap());
+
+        // Lines are still counted from the top of the input.
+        assert!(matches!(
+            parse_report("00100\n\n11210\n"),
+            Err(InputError::Parse { line: 3, .. })
+        ));
+    }
+}
@@ -0,0 +1,482 @@
+use utils::input::{parse_csv_line, InputError};
+use utils::{Answer, Solution, SolveError};
+
+pub type BingoLine = Vec<BingoNumber>;
+
+pub type Board = Vec<BingoLine>;
+
+/// A board whose numbers are marked as they are drawn.
+pub trait BingoBoard {
+    fn check_number(&mut self, drawn: i32);
+    fn win(&self) -> bool;
+    fn get_final_number(&self) -> i32;
+}
+
+impl BingoBoard for Board {
+    fn check_number(&mut self, drawn: i32) {
+        for line in self {
+            for bnumber in line {
+                bnumber.check(drawn)
+            }
+        }
+    }
+
+    fn win(&self) -> bool {
+        let mut winner: bool;
+        for line in self {
+            winner = true;
+            for bnumber in line {
+                winner = winner && bnumber.checked;
+            }
+            if winner {
+                return winner;
+            }
+        }
+
+        for i in 0..5 {
+            winner = true;
+            for line in self {
+                winner = winner && line[i].checked
+            }
+            if winner {
+                return winner;
+            }
+        }
+        false
+    }
+
+    fn get_final_number(&self) -> i32 {
+        let mut res = 0;
+        for line in self {
+            for bnumber in line {
+                if !bnumber.checked {
+                    res += bnumber.value;
+                }
+            }
+        }
+        res
+    }
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct BingoNumber {
+    pub value: i32,
+    pub checked: bool,
+}
+
+impl BingoNumber {
+    fn new(value: i32) -> Self {
+        BingoNumber {
+            value,
+            checked: false,
+        }
+    }
+    fn check(&mut self, other: i32) {
+        if self.value == other {
+            self.checked = true
+        }
+    }
+}
+
+impl From<i32> for BingoNumber {
+    fn from(value: i32) -> Self {
+        BingoNumber::new(value)
+    }
+}
+
+/// The draws, in order, and the boards.
+pub struct Bingo {
+    pub tirages: Vec<i32>,
+    pub boards: Vec<Board>,
+}
+
+/// Reads the comma separated draws, then boards of 5 rows of 5 numbers,
+/// each board after a blank line.
+fn parse_bingo(input: &str) -> Result<Bingo, InputError> {
+    let lines: Vec<&str> = input.lines().collect();
+
+    let first = lines.first().copied().unwrap_or_default();
+    let tirages = parse_csv_line(first)?;
+
+    let mut boards: Vec<Board> = Vec::new();
+    for (chunk, raw_board) in lines[1.min(lines.len())..].chunks(6).enumerate() {
+        let line_number = |row: usize| 2 + chunk * 6 + row;
+        if raw_board.iter().all(|line| line.trim().is_empty()) {
+            continue;
+        }
+
+        if !raw_board[0].trim().is_empty() {
+            return Err(InputError::parse(
+                line_number(0),
+                1,
+                "expected a blank line before each board",
+            ));
+        }
+        if raw_board.len() != 6 {
+            return Err(InputError::parse(
+                line_number(raw_board.len()),
+                1,
+                "a board has 5 rows",
+            ));
+        }
+
+        let mut board: Board = Vec::new();
+        for (row, line) in raw_board[1..].iter().enumerate() {
+            let numbers: Vec<BingoNumber> = line
+                .split_whitespace()
+                .map(|n| n.parse::<i32>().map(BingoNumber::new))
+                .collect::<Result<_, _>>()
+                .map_err(|e| InputError::parse(line_number(row + 1), 1, e))?;
+            if numbers.len() != 5 {
+                return Err(InputError::parse(
+                    line_number(row + 1),
+                    1,
+                    format!("expected 5 numbers, found {}", numbers.len()),
+                ));
+            }
+            board.push(numbers);
+        }
+        boards.push(board);
+    }
+
+    Ok(Bingo { tirages, boards })
+}
+
+/// Scores of the boards, in the order in which they win.
+fn play(bingo: &Bingo) -> Vec<i32> {
+    let mut boards: Vec<Board> = bingo.boards.clone();
+    let mut won: Vec<bool> = vec![false; boards.len()];
+    let mut scores: Vec<i32> = Vec::new();
+
+    for tirage in &bingo.tirages {
+        for (board, won) in boards.iter_mut().zip(won.iter_mut()) {
+            if *won {
+                continue;
+            }
+            board.check_number(*tirage);
+            if board.win() {
+                *won = true;
+                scores.push(board.get_final_number() * tirage);
+            }
+        }
+    }
+    scores
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input = Bingo;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        parse_bingo(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        match play(input).first() {
+            Some(score) => Ok((*score).into()),
+            None => Err(SolveError::new("no board wins")),
+        }
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        match play(input).last() {
+            Some(score) => Ok((*score).into()),
+            None => Err(SolveError::new("no board wins")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use proptest::prelude::*;
+    use utils::gen;
+
+    #[test]
+    #[allow(clippy::vec_init_then_push)]
+    fn test_win() {
+        let mut array: Board = Default::default();
+        array.push(Vec::from([
+            BingoNumber::new(1),
+            BingoNumber::new(2),
+            BingoNumber::new(3),
+            BingoNumber::new(4),
+            BingoNumber::new(5),
+        ]));
+        array.push(Vec::from([
+            BingoNumber::new(1),
+            BingoNumber::new(2),
+            BingoNumber::new(3),
+            BingoNumber::new(4),
+            BingoNumber::new(5),
+        ]));
+        array.push(Vec::from([
+            BingoNumber::new(1),
+            BingoNumber::new(2),
+            BingoNumber::new(3),
+            BingoNumber::new(4),
+            BingoNumber::new(5),
+        ]));
+        array.push(Vec::from([
+            BingoNumber::new(1),
+            BingoNumber::new(2),
+            BingoNumber::new(3),
+            BingoNumber::new(4),
+            BingoNumber::new(5),
+        ]));
+        array.push(Vec::from([
+            BingoNumber::new(1),
+            BingoNumber::new(2),
+            BingoNumber::new(3),
+            BingoNumber::new(4),
+            BingoNumber::new(5),
+        ]));
+
+        for number in [1, 2, 3, 4, 5] {
+            array.check_number(number);
+        }
+
+        assert!(array.win());
+        let mut array: Board = Default::default();
+        array.push(Vec::from([
+            BingoNumber::new(1),
+            BingoNumber::new(2),
+            BingoNumber::new(3),
+            BingoNumber::new(4),
+            BingoNumber::new(5),
+        ]));
+        array.push(Vec::from([
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+        ]));
+        array.push(Vec::from([
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+        ]));
+        array.push(Vec::from([
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+        ]));
+        array.push(Vec::from([
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+        ]));
+
+        for number in [1, 2, 3, 4, 6] {
+            array.check_number(number);
+        }
+        assert!(!array.win());
+
+        let mut array: Board = Default::default();
+        array.push(Vec::from([
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(1),
+        ]));
+        array.push(Vec::from([
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(2),
+        ]));
+        array.push(Vec::from([
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(3),
+        ]));
+        array.push(Vec::from([
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(4),
+        ]));
+        array.push(Vec::from([
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(5),
+        ]));
+        for number in [1, 2, 3, 4, 5] {
+            array.check_number(number);
+        }
+        assert!(array.win());
+
+        let mut array: Board = Default::default();
+        array.push(Vec::from([
+            BingoNumber::new(1),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+        ]));
+        array.push(Vec::from([
+            BingoNumber::new(2),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+        ]));
+        array.push(Vec::from([
+            BingoNumber::new(3),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+        ]));
+        array.push(Vec::from([
+            BingoNumber::new(4),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+        ]));
+        array.push(Vec::from([
+            BingoNumber::new(5),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+            BingoNumber::new(9),
+        ]));
+        for number in [1, 2, 3, 4, 6] {
+            array.check_number(number);
+        }
+        assert!(!array.win());
+
+        let mut array: Board = Default::default();
+        array.push(Vec::from([
+            BingoNumber::new(22),
+            BingoNumber::new(59),
+            BingoNumber::new(7),
+            BingoNumber::new(10),
+            BingoNumber::new(96),
+        ]));
+        array.push(Vec::from([
+            BingoNumber::new(33),
+            BingoNumber::new(36),
+            BingoNumber::new(96),
+            BingoNumber::new(55),
+            BingoNumber::new(23),
+        ]));
+        array.push(Vec::from([
+            BingoNumber::new(13),
+            BingoNumber::new(85),
+            BingoNumber::new(18),
+            BingoNumber::new(29),
+            BingoNumber::new(28),
+        ]));
+        array.push(Vec::from([
+            BingoNumber::new(75),
+            BingoNumber::new(46),
+            BingoNumber::new(83),
+            BingoNumber::new(73),
+            BingoNumber::new(58),
+        ]));
+        array.push(Vec::from([
+            BingoNumber::new(34),
+            BingoNumber::new(40),
+            BingoNumber::new(87),
+            BingoNumber::new(56),
+            BingoNumber::new(98),
+        ]));
+        for number in [
+            6, 69, 28, 50, 36, 84, 49, 13, 48, 90, 1, 33, 71, 0, 94, 59, 53, 58, 60, 96, 30, 34,
+            29, 91, 11, 41, 77, 95, 17, 80, 85, 93, 7, 9, 74, 89, 18,
+        ] {
+            array.check_number(number);
+        }
+        assert!(array.win());
+    }
+
+    /// Index of the draw that completes a row or column of `board`, found by
+    /// looking up when each of its numbers is drawn.
+    fn winning_turn(board: &Board, draws: &[i32]) -> Option<usize> {
+        let turn = |n: &BingoNumber| draws.iter().position(|&d| d == n.value);
+        let line_turn = |line: Vec<&BingoNumber>| {
+            line.into_iter()
+                .map(turn)
+                .collect::<Option<Vec<usize>>>()
+                .and_then(|turns| turns.into_iter().max())
+        };
+
+        let rows = board.iter().map(|row| line_turn(row.iter().collect()));
+        let columns = (0..5).map(|x| line_turn(board.iter().map(|row| &row[x]).collect()));
+        rows.chain(columns).flatten().min()
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let board = "\n\n 1 2 3 4 5\n 6 7 8 9 10\n11 12 13 14 15\n16 17 18 19 20\n21 22 23 24 25\n";
+        let bingo = parse_bingo(&format!("7,4,9{}\n\n", board)).unwrap();
+        assert_eq!(1, bingo.boards.len());
+
+        assert!(matches!(
+            parse_bingo(&format!("7,x,9{}", board)),
+            Err(InputError::Parse {
+                line: 1,
+                column: 3,
+                ..
+            })
+        ));
+        assert!(matches!(
+            parse_bingo(&format!("7,4{}", board.replace("8 9", "8"))),
+            Err(InputError::Parse { line: 4, .. })
+        ));
+        assert!(matches!(
+            parse_bingo("7,4\n\n1 2 3 4 5\n"),
+            Err(InputError::Parse { line: 4, .. })
+        ));
+        assert!(parse_bingo("").is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn test_winners_match_brute_force(text in gen::bingo(1..6)) {
+            let bingo = Day4::parse(&text).unwrap();
+            prop_assert_eq!(bingo.boards.len(), play(&bingo).len());
+
+            let turns: Vec<usize> = bingo
+                .boards
+                .iter()
+                .map(|board| winning_turn(board, &bingo.tirages).unwrap())
+                .collect();
+            let score = |index: usize| {
+                let drawn = &bingo.tirages[..=turns[index]];
+                let unmarked: i32 = bingo.boards[index]
+                    .iter()
+                    .flatten()
+                    .map(|n| n.value)
+                    .filter(|value| !drawn.contains(value))
+                    .sum();
+                Answer::from(unmarked * bingo.tirages[turns[index]])
+            };
+
+            // Boards winning on the same draw are scored in input order.
+            let first = (0..turns.len()).min_by_key(|&i| turns[i]).unwrap();
+            let last = (0..turns.len()).max_by_key(|&i| turns[i]).unwrap();
+            prop_assert_eq!(Ok(score(first)), Day4::part1(&bingo));
+            prop_assert_eq!(Ok(score(last)), Day4::part2(&bingo));
+        }
+    }
+}
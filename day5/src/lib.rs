@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use utils::input::{parse_lines, InputError};
+use utils::{Answer, Point, Solution, SolveError, Vec2};
+
+#[derive(Default, Debug)]
+pub struct Line {
+    start: Point<i32>,
+    end: Point<i32>,
+}
+
+/// Parses `0,9 -> 5,9`.
+impl FromStr for Line {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Line, String> {
+        let (start, end) = input
+            .split_once(" -> ")
+            .ok_or_else(|| format!("expected \"x1,y1 -> x2,y2\", found {:?}", input))?;
+        let start = start.parse().map_err(|e| format!("start {}", e))?;
+        let end: Point<i32> = end.parse().map_err(|e| format!("end {}", e))?;
+
+        let line = Line { start, end };
+        let dx = i64::from(end.x) - i64::from(start.x);
+        let dy = i64::from(end.y) - i64::from(start.y);
+        if !line.is_along_axes() && dx.abs() != dy.abs() {
+            return Err(format!("{:?} is neither straight nor at 45 degrees", input));
+        }
+        Ok(line)
+    }
+}
+
+impl Line {
+    fn is_along_axes(&self) -> bool {
+        self.start.x == self.end.x || self.start.y == self.end.y
+    }
+
+    fn compute_points(&self) -> Vec<Point<i32>> {
+        let delta = self.end - self.start;
+        let step = Vec2::new(delta.x.signum(), delta.y.signum());
+
+        let mut result: Vec<Point<i32>> = Vec::new();
+        let mut point = self.start;
+        result.push(point);
+
+        while point != self.end {
+            point += step;
+            result.push(point);
+        }
+
+        result
+    }
+}
+
+fn count_overlaps<'a, I>(lines: I) -> usize
+where
+    I: Iterator<Item = &'a Line>,
+{
+    let points: Vec<Point<i32>> = lines.flat_map(|l| l.compute_points()).collect();
+
+    let mut result: HashMap<Point<i32>, i32> = HashMap::new();
+
+    for item in points {
+        *result.entry(item).or_insert(0) += 1;
+    }
+
+    result.into_iter().filter(|(_, v)| *v >= 2).count()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input = Vec<Line>;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        parse_lines(input)
+    }
+
+    fn part1(lines: &Self::Input) -> Result<Answer, SolveError> {
+        let axis_lines = lines.iter().filter(|l| l.is_along_axes());
+        Ok(count_overlaps(axis_lines).into())
+    }
+
+    fn part2(lines: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(count_overlaps(lines.iter()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use utils::gen;
+
+    #[test]
+    fn test_compute_points() {
+        let line: Line = "9,7 -> 7,9".parse().unwrap();
+        assert!(!line.is_along_axes());
+        assert_eq!(
+            vec![Point::new(9, 7), Point::new(8, 8), Point::new(7, 9)],
+            line.compute_points()
+        );
+
+        let line: Line = "1,1 -> 1,3".parse().unwrap();
+        assert!(line.is_along_axes());
+        assert_eq!(3, line.compute_points().len());
+    }
+
+    #[test]
+    fn test_example() {
+        let lines = Day5::parse(
+            "0,9 -> 5,9\n8,0 -> 0,8\n9,4 -> 3,4\n2,2 -> 2,1\n7,0 -> 7,4\n\
+             6,4 -> 2,0\n0,9 -> 2,9\n3,4 -> 1,4\n0,0 -> 8,8\n5,5 -> 8,2\n",
+        )
+        .unwrap();
+
+        assert_eq!(Ok(Answer::Int(5)), Day5::part1(&lines));
+        assert_eq!(Ok(Answer::Int(12)), Day5::part2(&lines));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(matches!(
+            Day5::parse("0,9 -> 5,9\n8,0 => 0,8\n"),
+            Err(InputError::Parse { line: 2, .. })
+        ));
+        assert!("0,9 -> 5;9".parse::<Line>().is_err());
+        assert!("0,0 -> 2,1".parse::<Line>().is_err());
+        assert!("8,0 -> 0,8".parse::<Line>().is_ok());
+    }
+
+    /// Points covered by at least two lines, testing every point of the
+    /// `size` x `size` square against every line.
+    fn raster_overlaps(lines: &[&Line], size: i32) -> usize {
+        let covers = |line: &Line, x: i32, y: i32| {
+            let (a, b) = (line.start, line.end);
+            let cross = (b.x - a.x) * (y - a.y) - (b.y - a.y) * (x - a.x);
+            cross == 0
+                && a.x.min(b.x) <= x
+                && x <= a.x.max(b.x)
+                && a.y.min(b.y) <= y
+                && y <= a.y.max(b.y)
+        };
+
+        (0..size)
+            .flat_map(|y| (0..size).map(move |x| (x, y)))
+            .filter(|&(x, y)| lines.iter().filter(|l| covers(l, x, y)).count() >= 2)
+            .count()
+    }
+
+    proptest! {
+        #[test]
+        fn test_overlaps_match_raster(text in gen::vent_lines(12, 1..25)) {
+            let lines = Day5::parse(&text).unwrap();
+            let all: Vec<&Line> = lines.iter().collect();
+            let axes: Vec<&Line> = lines.iter().filter(|l| l.is_along_axes()).collect();
+
+            prop_assert_eq!(Ok(Answer::from(raster_overlaps(&axes, 12))), Day5::part1(&lines));
+            prop_assert_eq!(Ok(Answer::from(raster_overlaps(&all, 12))), Day5::part2(&lines));
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use std::str::FromStr;
+use utils::input::{parse_csv_line, InputError};
+use utils::{Answer, Solution, SolveError};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        parse_lanterns(input)
+    }
+
+    fn part1(lanterns: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(count_after(80, lanterns).into())
+    }
+
+    fn part2(lanterns: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(count_after(256, lanterns).into())
+    }
+}
+
+fn count_after(nb_grow: i32, lanterns: &[usize]) -> i128 {
+    let lanterns = count_lanterns(lanterns.to_vec());
+    let lanterns = grow(nb_grow, lanterns);
+    lanterns.iter().sum()
+}
+
+/// A lanternfish internal timer, never above 8.
+struct Timer(usize);
+
+impl FromStr for Timer {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse::<usize>() {
+            Ok(timer) if timer <= 8 => Ok(Timer(timer)),
+            Ok(timer) => Err(format!("timer {} is out of range 0..=8", timer)),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+/// Reads the comma separated internal timers.
+fn parse_lanterns(input: &str) -> Result<Vec<usize>, InputError> {
+    let timers: Vec<Timer> = parse_csv_line(input)?;
+    Ok(timers.into_iter().map(|Timer(timer)| timer).collect())
+}
+
+fn count_lanterns(input: Vec<usize>) -> [i128; 9] {
+    let mut output: [i128; 9] = [0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+    for num in input {
+        output[num] += 1;
+    }
+    output
+}
+
+fn grow(nb_grow: i32, mut population: [i128; 9]) -> [i128; 9] {
+    for _grow in 0..nb_grow {
+        let pop0 = population[0];
+
+        population[0] = population[1];
+        population[1] = population[2];
+        population[2] = population[3];
+        population[3] = population[4];
+        population[4] = population[5];
+        population[5] = population[6];
+        population[6] = population[7] + pop0;
+        population[7] = population[8];
+        population[8] = pop0;
+    }
+
+    population
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use utils::gen;
+
+    #[test]
+    fn test_equality() {
+        let input: String = "3,4,3,1,2".to_string();
+
+        let population = parse_lanterns(&input).unwrap();
+
+        let expected: Vec<usize> = vec![3, 4, 3, 1, 2];
+
+        assert_eq!(expected, population);
+
+        let expected: [i128; 9] = [0, 1, 1, 2, 1, 0, 0, 0, 0];
+
+        let pop = count_lanterns(population);
+
+        assert_eq!(expected, pop);
+
+        let grow1_expected: Vec<usize> = vec![2, 3, 2, 0, 1];
+        let grow1_expected = count_lanterns(grow1_expected);
+
+        let grow1 = grow(1, pop);
+
+        assert_eq!(grow1_expected, grow1);
+    }
+
+    #[test]
+    fn test_parse_out_of_range() {
+        assert!(matches!(
+            parse_lanterns("3,4,9,1"),
+            Err(InputError::Parse {
+                line: 1,
+                column: 5,
+                ..
+            })
+        ));
+        assert!(matches!(
+            parse_lanterns("3,4, 9,1\n"),
+            Err(InputError::Parse { column: 6, .. })
+        ));
+        assert!(matches!(
+            parse_lanterns("3,4,x"),
+            Err(InputError::Parse { column: 5, .. })
+        ));
+    }
+
+    /// Simulates every fish on its own, as the puzzle text describes.
+    fn simulate(timers: &[usize], days: i32) -> usize {
+        let mut school = timers.to_vec();
+        for _ in 0..days {
+            let newborns = school.iter().filter(|&&timer| timer == 0).count();
+            for timer in school.iter_mut() {
+                *timer = if *timer == 0 { 6 } else { *timer - 1 };
+            }
+            school.extend(std::iter::repeat_n(8, newborns));
+        }
+        school.len()
+    }
+
+    proptest! {
+        #[test]
+        fn test_buckets_match_simulation(text in gen::lanternfish(1..6), days in 0..70) {
+            let lanterns = Day6::parse(&text).unwrap();
+            prop_assert_eq!(simulate(&lanterns, days) as i128, count_after(days, &lanterns));
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use utils::input::{parse_csv_line, InputError};
+use utils::{Answer, Solution, SolveError};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        parse_csv_line(input)
+    }
+
+    fn part1(numbers: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(compute_smallest_part1(numbers.clone()).into())
+    }
+
+    fn part2(numbers: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(compute_smallest_part2(numbers.clone()).into())
+    }
+}
+
+fn compute_smallest_part1(numbers: Vec<i32>) -> i32 {
+    let mut fuel_consumption: Vec<i32> = Vec::new();
+
+    for nb in 0..numbers.len() {
+        fuel_consumption.insert(nb, numbers.iter().map(|n| (n - nb as i32).abs()).sum());
+    }
+    fuel_consumption.sort();
+    fuel_consumption[0]
+}
+
+fn compute_smallest_part2(numbers: Vec<i32>) -> i32 {
+    let mut fuel_consumption: Vec<i32> = Vec::new();
+
+    for nb in 0..numbers.len() {
+        fuel_consumption.insert(
+            nb,
+            numbers
+                .iter()
+                .map(|n| (n - nb as i32).abs())
+                .map(|steps| (1..steps + 1).sum::<i32>())
+                .sum(),
+        );
+    }
+    fuel_consumption.sort();
+    fuel_consumption[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_equality() {
+        let numbers = vec![16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
+
+        assert_eq!(37, compute_smallest_part1(numbers.clone()));
+
+        assert_eq!(168, compute_smallest_part2(numbers));
+    }
+}
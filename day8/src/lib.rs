@@ -0,0 +1,317 @@
+use std::char;
+use std::collections::HashSet;
+use utils::input::InputError;
+use utils::{Answer, Solution, SolveError};
+
+#[derive(Debug, Clone)]
+pub struct Signal {
+    pattern: Vec<String>,
+    digit: Vec<String>,
+    segments: Vec<String>,
+    value: i32,
+}
+
+impl Signal {
+    /// Decodes a note, failing when it is not ten distinct patterns and four
+    /// outputs that can be wired consistently.
+    fn new(mut pattern: Vec<String>, digit: Vec<String>) -> Result<Self, String> {
+        if pattern.len() != 10 || digit.len() != 4 {
+            return Err(format!(
+                "expected 10 patterns and 4 digits, found {} and {}",
+                pattern.len(),
+                digit.len()
+            ));
+        }
+        for word in pattern.iter().chain(&digit) {
+            let letters: HashSet<char> = word.chars().collect();
+            if word.is_empty()
+                || letters.len() != word.len()
+                || !letters.iter().all(|c| ('a'..='g').contains(c))
+            {
+                return Err(format!("{:?} is not a set of segments a-g", word));
+            }
+        }
+        pattern.sort_by_key(|a| a.len());
+        let lengths: Vec<usize> = pattern.iter().map(String::len).collect();
+        if lengths != [2, 3, 4, 5, 5, 5, 6, 6, 6, 7] {
+            return Err(format!("patterns of lengths {:?} are not 0-9", lengths));
+        }
+
+        let mut sig = Signal {
+            pattern,
+            digit,
+            segments: Vec::new(),
+            value: 0,
+        };
+        sig.compute_segments();
+        sig.compute_number()?;
+        Ok(sig)
+    }
+
+    /*
+          0:      1:      2:      3:      4:
+         aaaa    ....    aaaa    aaaa    ....
+        b    c  .    c  .    c  .    c  b    c
+        b    c  .    c  .    c  .    c  b    c
+         ....    ....    dddd    dddd    dddd
+        e    f  .    f  e    .  .    f  .    f
+        e    f  .    f  e    .  .    f  .    f
+         gggg    ....    gggg    gggg    ....
+
+          5:      6:      7:      8:      9:
+         aaaa    aaaa    aaaa    aaaa    aaaa
+        b    .  b    .  .    c  b    c  b    c
+        b    .  b    .  .    c  b    c  b    c
+         dddd    dddd    ....    dddd    dddd
+        .    f  e    f  .    f  e    f  .    f
+        .    f  e    f  .    f  e    f  .    f
+         gggg    gggg    ....    gggg    gggg
+
+    0 : 6 abcefg
+    1 : 2 cf
+    2 : 5 acdeg
+    3 : 5 acdfg
+    4 : 4 bcdf
+    5 : 5 abdfg
+    6 : 6 abdefg
+    7 : 3 acf
+    8 : 7 abcdefg
+    9 : 6 abcdfg
+
+    1 : 2 cf
+    7 : 3 acf
+    4 : 4 bcdf
+    2 : 5 acdeg
+    3 : 5 acdfg
+    5 : 5 abdfg
+    6 : 6 abdefg
+    0 : 6 abcefg
+    9 : 6 abcdfg
+    8 : 7 abcdefg
+
+        */
+
+    fn compute_segments(&mut self) {
+        let one = &self.pattern[0];
+        let seven = &self.pattern[1];
+        let four = &self.pattern[2];
+        let eight = &self.pattern[9];
+
+        let mut zero: String = "".to_string();
+        let mut two: String = "".to_string();
+        let mut three: String = "".to_string();
+        let mut five: String = "".to_string();
+        let mut six: String = "".to_string();
+        let mut nine: String = "".to_string();
+
+        for i in 3..=5 {
+            let tmp = &self.pattern[i];
+            if contains(one, tmp) {
+                three = tmp.to_string();
+            } else if count_commons(four, tmp) == 2 {
+                five = tmp.to_string();
+            } else {
+                two = tmp.to_string();
+            }
+        }
+
+        for i in 6..=8 {
+            let tmp = &self.pattern[i];
+            if contains(four, tmp) {
+                nine = tmp.to_string();
+            }
+            //reste 6 et 0
+            else if contains(one, tmp) {
+                zero = tmp.to_string();
+            } else {
+                six = tmp.to_string();
+            }
+        }
+        self.segments.push(zero);
+        self.segments.push(one.to_string());
+        self.segments.push(two);
+        self.segments.push(three);
+        self.segments.push(four.to_string());
+        self.segments.push(five.to_string());
+        self.segments.push(six);
+        self.segments.push(seven.to_string());
+        self.segments.push(eight.to_string());
+        self.segments.push(nine.to_string());
+    }
+
+    fn compute_number(&mut self) -> Result<(), String> {
+        let mut value = 0;
+        for di in &self.digit {
+            let matches: Vec<i32> = (0..=9)
+                .filter(|&i| count_commons(&self.segments[i as usize], di) == 0)
+                .collect();
+            match matches[..] {
+                [i] => value = value * 10 + i,
+                _ => return Err(format!("{:?} is not a single digit", di)),
+            }
+        }
+        self.value = value;
+        Ok(())
+    }
+}
+
+fn contains(a: &String, b: &String) -> bool {
+    // get which one is shorter
+    let (shorter, longer) = if b.len() > a.len() { (a, b) } else { (b, a) };
+
+    // fill the set with the characters from the shorter string
+    let set: HashSet<char> = longer.chars().collect();
+
+    shorter.chars().all(|c| set.contains(&c))
+}
+
+fn count_commons(a: &String, b: &String) -> i32 {
+    let (shorter, longer) = if a.len() > b.len() { (b, a) } else { (a, b) };
+
+    let mut tmp = longer.clone();
+
+    for mychar in shorter.chars() {
+        tmp = tmp.replace(mychar, "");
+    }
+
+    tmp.len() as i32
+}
+
+fn parse_entries(input: Vec<String>) -> Result<Vec<Signal>, InputError> {
+    let mut result: Vec<Signal> = Vec::new();
+    for (index, line) in input.iter().enumerate() {
+        let (pattern, digit) = line
+            .split_once(" | ")
+            .ok_or_else(|| InputError::parse(index + 1, 1, "expected \"patterns | digits\""))?;
+
+        let pattern: Vec<String> = pattern.split(' ').map(|s| s.to_string()).collect();
+        let digit: Vec<String> = digit.split(' ').map(|s| s.to_string()).collect();
+
+        let signal = Signal::new(pattern, digit).map_err(|e| InputError::parse(index + 1, 1, e))?;
+        result.push(signal)
+    }
+
+    Ok(result)
+}
+
+fn part1(signals: Vec<Signal>) -> u32 {
+    let mut part1 = 0;
+    for signal in signals {
+        let filtered: Vec<&String> = signal
+            .digit
+            .iter()
+            .filter(|d| d.len() == 2 || d.len() == 4 || d.len() == 7 || d.len() == 3)
+            .collect();
+        part1 += filtered.len();
+    }
+    part1 as u32
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Input = Vec<Signal>;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        parse_entries(input.lines().map(String::from).collect())
+    }
+
+    fn part1(signals: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part1(signals.clone()).into())
+    }
+
+    fn part2(signals: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(signals.iter().map(|t| t.value).sum::<i32>().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use utils::gen;
+
+    #[test]
+    fn test_equality() {
+        let test_data:Vec<String> = vec!["be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe".into(),
+                                         "edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc".into(),
+                                         "fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg".into(),
+                                         "fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb".into(),
+                                         "aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea".into(),
+                                         "fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb".into(),
+                                         "dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe".into(),
+                                         "bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef".into(),
+                                         "egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb".into(),
+                                         "gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce".into()];
+
+        let test = parse_entries(test_data.clone()).unwrap();
+
+        assert_eq!(26, part1(test));
+
+        let test = parse_entries(test_data).unwrap();
+        assert_eq!(61229, test.iter().map(|t| t.value).sum())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let note = "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe";
+        assert!(Day8::parse(note).is_ok());
+
+        let invalid = [
+            "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb",
+            "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd | fdgacbe cefdb cefbgd gcbe",
+            "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd",
+            "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edx | fdgacbe cefdb cefbgd gcbe",
+            "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gc",
+            "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edbe | fdgacbe cefdb cefbgd gcbe",
+        ];
+        for line in invalid {
+            let text = format!("{}\n{}\n", note, line);
+            assert!(
+                matches!(Day8::parse(&text), Err(InputError::Parse { line: 2, .. })),
+                "{}",
+                line
+            );
+        }
+    }
+
+    #[test]
+    fn test_share_chars() {
+        let three = "acdfg".to_string();
+        let one = "cf".to_string();
+        let two = "acdeg".to_string();
+
+        assert!(contains(&one, &three));
+        assert!(!contains(&one, &two));
+    }
+
+    #[test]
+    fn test_count_commons() {
+        let five = "abdfg".to_string();
+        let four = "bcdf".to_string();
+        let two = "abdeg".to_string();
+
+        assert_eq!(2, count_commons(&four, &five));
+        assert_eq!(3, count_commons(&four, &two));
+        assert_eq!(0, count_commons(&two, &two));
+    }
+
+    proptest! {
+        #[test]
+        fn test_decodes_scrambled_notes(notes in vec(gen::seven_segment(), 1..10)) {
+            let text: String = notes.iter().map(|(line, _)| format!("{}\n", line)).collect();
+            let signals = Day8::parse(&text).unwrap();
+
+            let easy = notes
+                .iter()
+                .flat_map(|(line, _)| line.split(" | ").nth(1).unwrap().split(' '))
+                .filter(|digit| matches!(digit.len(), 2 | 3 | 4 | 7))
+                .count();
+            let total: u32 = notes.iter().map(|(_, value)| value).sum();
+            prop_assert_eq!(Ok(Answer::from(easy)), Day8::part1(&signals));
+            prop_assert_eq!(Ok(Answer::from(total)), Day8::part2(&signals));
+        }
+    }
+}
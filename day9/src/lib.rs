@@ -0,0 +1,166 @@
+use std::collections::HashSet;
+use utils::input::InputError;
+use utils::{Answer, Grid, Point, Solution, SolveError};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Input = Grid<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        input.parse()
+    }
+
+    fn part1(map: &Self::Input) -> Result<Answer, SolveError> {
+        let lowers = find_lowers(map);
+        Ok(calc_risk(&lowers, map).into())
+    }
+
+    fn part2(map: &Self::Input) -> Result<Answer, SolveError> {
+        let lowers = find_lowers(map);
+
+        let mut part2_points: Vec<HashSet<Point<usize>>> = Vec::new();
+        for point in lowers {
+            part2_points.push(part2_neighbours(point, map));
+        }
+
+        let mut part2_counts: Vec<usize> = part2_points.iter().map(|s| s.len()).collect();
+
+        part2_counts.sort();
+
+        if part2_counts.len() < 3 {
+            return Err(SolveError::new("fewer than three basins"));
+        }
+
+        let count = part2_counts[part2_counts.len() - 1]
+            * part2_counts[part2_counts.len() - 2]
+            * part2_counts[part2_counts.len() - 3];
+
+        Ok(count.into())
+    }
+}
+
+fn find_lowers(map: &Grid<i32>) -> Vec<Point<usize>> {
+    map.positions()
+        .map(Point::from)
+        .filter(|&point| is_low_point(point, map))
+        .collect()
+}
+
+fn is_low_point(point: Point<usize>, map: &Grid<i32>) -> bool {
+    map.neighbours4(point.x, point.y)
+        .all(|neighbour| map[point] < map[neighbour])
+}
+
+fn calc_risk(points: &[Point<usize>], map: &Grid<i32>) -> i32 {
+    points.iter().map(|&p| map[p] + 1).sum()
+}
+
+fn part2_neighbours(point: Point<usize>, map: &Grid<i32>) -> HashSet<Point<usize>> {
+    let mut point_set: HashSet<Point<usize>> = HashSet::new();
+    get_neighbours_inner(&mut point_set, point, map);
+    point_set
+}
+
+fn get_neighbours_inner(
+    input_set: &mut HashSet<Point<usize>>,
+    point: Point<usize>,
+    map: &Grid<i32>,
+) {
+    if !input_set.contains(&point) && map[point] != 9 {
+        input_set.insert(point);
+
+        for neighbour in map.neighbours4(point.x, point.y) {
+            get_neighbours_inner(input_set, neighbour.into(), map);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use utils::gen;
+
+    #[test]
+    fn test_lower() {
+        let lines = "2199943210\n\
+                     3987894921\n\
+                     9856789892\n\
+                     8767896789\n\
+                     9899965678\n";
+
+        let map: Grid<i32> = lines.parse().unwrap();
+        let lowers = find_lowers(&map);
+        assert_eq!(15, calc_risk(&lowers, &map));
+
+        let mut part2_points: Vec<HashSet<Point<usize>>> = Vec::new();
+        for point in lowers {
+            part2_points.push(part2_neighbours(point, &map));
+            //lowers.iter().map(|l| ).collect();
+        }
+
+        let mut part2_counts: Vec<usize> = part2_points.iter().map(|s| s.len()).collect();
+
+        part2_counts.sort();
+
+        let count = part2_counts[part2_counts.len() - 1]
+            * part2_counts[part2_counts.len() - 2]
+            * part2_counts[part2_counts.len() - 3];
+        assert_eq!(1134, count)
+    }
+
+    /// Sum of the risk levels, comparing every height with its neighbours
+    /// directly on the rows.
+    fn brute_force_risk(rows: &[Vec<i32>]) -> i32 {
+        let height = |x: isize, y: isize| {
+            rows.get(y as usize)
+                .and_then(|row| row.get(x as usize))
+                .copied()
+                .unwrap_or(i32::MAX)
+        };
+
+        let mut risk = 0;
+        for (y, row) in rows.iter().enumerate() {
+            for (x, &h) in row.iter().enumerate() {
+                let (x, y) = (x as isize, y as isize);
+                let neighbours = [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)];
+                if neighbours.iter().all(|&(nx, ny)| h < height(nx, ny)) {
+                    risk += h + 1;
+                }
+            }
+        }
+        risk
+    }
+
+    proptest! {
+        #[test]
+        fn test_risk_matches_brute_force(text in gen::heightmap(1..15, 1..15)) {
+            let map = Day9::parse(&text).unwrap();
+            let rows: Vec<Vec<i32>> = text
+                .lines()
+                .map(|line| line.bytes().map(|b| (b - b'0') as i32).collect())
+                .collect();
+
+            prop_assert_eq!(Ok(Answer::from(brute_force_risk(&rows))), Day9::part1(&map));
+        }
+
+        #[test]
+        fn test_basins_are_closed(text in gen::heightmap(1..15, 1..15)) {
+            let map = Day9::parse(&text).unwrap();
+
+            for low in find_lowers(&map) {
+                let basin = part2_neighbours(low, &map);
+                prop_assert_eq!(map[low] != 9, basin.contains(&low));
+                for &point in &basin {
+                    prop_assert!(map[point] != 9);
+                    for neighbour in map.neighbours4(point.x, point.y) {
+                        let neighbour = Point::from(neighbour);
+                        prop_assert!(map[neighbour] == 9 || basin.contains(&neighbour));
+                    }
+                }
+            }
+        }
+    }
+}
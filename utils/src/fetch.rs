@@ -0,0 +1,343 @@
+//! Downloads puzzle inputs from adventofcode.com and keeps them in a cache,
+//! so that each input is only ever downloaded once.
+
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+pub const YEAR: u16 = 2021;
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable holding the `session` cookie of adventofcode.com.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable overriding [`DEFAULT_BASE_URL`].
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+const USER_AGENT: &str = "github.com/delvin1933/advent2021 input fetcher";
+
+#[derive(Debug)]
+pub enum FetchError {
+    /// Neither the environment variable nor the config file holds a session.
+    NoSession { config: Option<PathBuf> },
+    /// The server answered with an error status, e.g. 404 for a day that is
+    /// not unlocked yet or 400 for an expired session.
+    Status { code: u16, body: String },
+    /// The server could not be reached.
+    Transport(String),
+    /// The cache could not be read or written.
+    Io { path: PathBuf, source: io::Error },
+    /// The answer is empty or holds whitespace, so it cannot be submitted.
+    BadAnswer(String),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::NoSession { config: Some(path) } => write!(
+                f,
+                "no session cookie: set {} or write it to {}",
+                SESSION_VAR,
+                path.display()
+            ),
+            FetchError::NoSession { config: None } => {
+                write!(f, "no session cookie: set {}", SESSION_VAR)
+            }
+            FetchError::Status { code, body } => {
+                write!(f, "server answered {}: {}", code, body.trim())
+            }
+            FetchError::Transport(message) => write!(f, "cannot reach server: {}", message),
+            FetchError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            FetchError::BadAnswer(answer) => {
+                write!(f, "cannot submit {:?}: an answer is a single word", answer)
+            }
+        }
+    }
+}
+
+impl Error for FetchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FetchError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Where the session cookie can be stored instead of the environment.
+pub fn session_config_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("advent2021").join("session"))
+}
+
+/// The user's cache directory, e.g. `~/.cache/advent2021` on Linux.
+pub fn default_cache_dir() -> PathBuf {
+    dirs::cache_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("advent2021")
+}
+
+/// Reads the session cookie from [`SESSION_VAR`], then from `config`.
+pub fn find_session(config: Option<&Path>) -> Result<String, FetchError> {
+    session_from(std::env::var(SESSION_VAR).ok(), config)
+}
+
+fn session_from(from_env: Option<String>, config: Option<&Path>) -> Result<String, FetchError> {
+    let non_blank = |session: String| {
+        let session = session.trim();
+        (!session.is_empty()).then(|| session.to_string())
+    };
+    let from_file = || {
+        config
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(non_blank)
+    };
+
+    from_env
+        .and_then(non_blank)
+        .or_else(from_file)
+        .ok_or_else(|| FetchError::NoSession {
+            config: config.map(Path::to_path_buf),
+        })
+}
+
+#[derive(Debug, Clone)]
+pub struct Client {
+    base_url: String,
+    session: String,
+    cache_dir: PathBuf,
+}
+
+impl Client {
+    /// Inputs are cached under `cache_dir`, by year and day, so a new session
+    /// cookie for the same account still finds them. Use a separate
+    /// `cache_dir` for each account.
+    pub fn new(base_url: &str, session: &str, cache_dir: impl Into<PathBuf>) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            cache_dir: cache_dir.into(),
+        }
+    }
+
+    /// A client set up from the environment: [`BASE_URL_VAR`] or the real
+    /// site, the session from [`find_session`], and the user's cache
+    /// directory.
+    pub fn from_env() -> Result<Self, FetchError> {
+        let base_url = std::env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        let session = find_session(session_config_path().as_deref())?;
+
+        Ok(Client::new(&base_url, &session, default_cache_dir()))
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Folder holding everything cached for [`YEAR`].
+    pub fn year_dir(&self) -> PathBuf {
+        self.cache_dir.join(YEAR.to_string())
+    }
+
+    pub fn cache_path(&self, day: u8) -> PathBuf {
+        self.year_dir().join(format!("day{}.txt", day))
+    }
+
+    /// The input of `day`, from the cache when it has already been
+    /// downloaded.
+    pub fn input(&self, day: u8) -> Result<String, FetchError> {
+        let path = self.cache_path(day);
+        if path.is_file() {
+            return read(&path);
+        }
+
+        let url = format!("{}/{}/day/{}/input", self.base_url, YEAR, day);
+        let text = self.get(&url)?;
+
+        let io_error = |source| FetchError::Io {
+            path: path.clone(),
+            source,
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(io_error)?;
+        }
+        fs::write(&path, &text).map_err(io_error)?;
+
+        Ok(text)
+    }
+
+    fn get(&self, url: &str) -> Result<String, FetchError> {
+        let request = ureq::get(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT);
+        body(request.call())
+    }
+
+    /// Posts a form under the base URL, e.g. `/2021/day/1/answer`.
+    pub(crate) fn post_form(
+        &self,
+        path: &str,
+        form: &[(&str, &str)],
+    ) -> Result<String, FetchError> {
+        let request = ureq::post(&format!("{}{}", self.base_url, path))
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT);
+        body(request.send_form(form))
+    }
+}
+
+fn body(response: Result<ureq::Response, ureq::Error>) -> Result<String, FetchError> {
+    match response {
+        Ok(response) => {
+            let mut text = String::new();
+            response
+                .into_reader()
+                .read_to_string(&mut text)
+                .map_err(|e| FetchError::Transport(e.to_string()))?;
+            Ok(text)
+        }
+        Err(ureq::Error::Status(code, response)) => Err(FetchError::Status {
+            code,
+            body: response.into_string().unwrap_or_default(),
+        }),
+        Err(error) => Err(FetchError::Transport(error.to_string())),
+    }
+}
+
+fn read(path: &Path) -> Result<String, FetchError> {
+    fs::read_to_string(path).map_err(|source| FetchError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+
+    /// A local stand-in for adventofcode.com: answers every request with
+    /// `handler(method, url, cookie, body)` and counts the requests.
+    pub(crate) fn mock_server<F>(handler: F) -> (String, Arc<AtomicUsize>)
+    where
+        F: Fn(&str, &str, &str, &str) -> (u16, String) + Send + 'static,
+    {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&hits);
+
+        thread::spawn(move || {
+            for mut request in server.incoming_requests() {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let cookie = request
+                    .headers()
+                    .iter()
+                    .find(|h| h.field.equiv("Cookie"))
+                    .map(|h| h.value.to_string())
+                    .unwrap_or_default();
+                let mut body = String::new();
+                request.as_reader().read_to_string(&mut body).unwrap();
+
+                let method = request.method().to_string();
+                let (code, text) = handler(&method, request.url(), &cookie, &body);
+                let _ =
+                    request.respond(tiny_http::Response::from_string(text).with_status_code(code));
+            }
+        });
+
+        (base_url, hits)
+    }
+
+    pub(crate) fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("utils-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_download_once() {
+        let (base_url, hits) = mock_server(|_, url, cookie, _| match (url, cookie) {
+            ("/2021/day/7/input", "session=abc") => (200, "16,1,2,0,4,2,7,1,2,14\n".to_string()),
+            _ => (404, "Not Found".to_string()),
+        });
+        let cache = temp_dir("fetch-once");
+        let client = Client::new(&base_url, "abc", &cache);
+
+        assert_eq!("16,1,2,0,4,2,7,1,2,14\n", client.input(7).unwrap());
+        assert_eq!("16,1,2,0,4,2,7,1,2,14\n", client.input(7).unwrap());
+        assert_eq!(1, hits.load(Ordering::SeqCst));
+        assert!(client.cache_path(7).is_file());
+
+        fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn test_cache_survives_new_session() {
+        let (base_url, hits) = mock_server(|_, _, cookie, _| (200, cookie.to_string()));
+        let cache = temp_dir("fetch-session");
+
+        let before = Client::new(&base_url, "expired", &cache);
+        let after = Client::new(&base_url, "renewed", &cache);
+        assert_eq!(before.cache_path(1), after.cache_path(1));
+        assert_eq!("session=expired", before.input(1).unwrap());
+        assert_eq!("session=expired", after.input(1).unwrap());
+        assert_eq!(1, hits.load(Ordering::SeqCst));
+
+        fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn test_error_status_is_not_cached() {
+        let (base_url, hits) = mock_server(|_, _, _, _| {
+            (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!".to_string(),
+            )
+        });
+        let cache = temp_dir("fetch-404");
+        let client = Client::new(&base_url, "abc", &cache);
+
+        match client.input(25) {
+            Err(FetchError::Status { code: 404, .. }) => (),
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(!client.cache_path(25).exists());
+        assert!(client.input(25).is_err());
+        assert_eq!(2, hits.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_unreachable_server() {
+        let client = Client::new("http://127.0.0.1:1", "abc", temp_dir("fetch-down"));
+        assert!(matches!(client.input(1), Err(FetchError::Transport(_))));
+    }
+
+    #[test]
+    fn test_session_from_config() {
+        let dir = temp_dir("fetch-config");
+        fs::create_dir_all(&dir).unwrap();
+        let config = dir.join("session");
+        fs::write(&config, "  cafe42\n").unwrap();
+
+        assert_eq!("cafe42", session_from(None, Some(&config)).unwrap());
+        assert_eq!(
+            "beef",
+            session_from(Some("beef".to_string()), Some(&config)).unwrap()
+        );
+        assert!(matches!(
+            session_from(None, Some(&dir.join("missing"))),
+            Err(FetchError::NoSession { config: Some(_) })
+        ));
+        assert!(session_from(Some(" \n".to_string()), None).is_err());
+        // A blank variable falls back to the config file.
+        assert_eq!(
+            "cafe42",
+            session_from(Some(String::new()), Some(&config)).unwrap()
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,413 @@
+//! `proptest` strategies producing valid puzzle inputs, in the exact text
+//! format of each day, for property tests. Where generating an input
+//! reveals its answer for free, such as the value behind a scrambled
+//! display, the strategy yields it too.
+
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::ops::Range;
+
+use proptest::collection::{vec, SizeRange};
+use proptest::prelude::*;
+
+/// A bingo game (day 4) where every number of `0..100` is drawn, so that
+/// every board eventually wins. Each board holds 25 distinct numbers.
+pub fn bingo(boards: impl Into<SizeRange>) -> impl Strategy<Value = String> {
+    let numbers = || Just((0..100).collect::<Vec<u32>>()).prop_shuffle();
+    let board = numbers().prop_map(|mut numbers| {
+        numbers.truncate(25);
+        numbers
+    });
+
+    (numbers(), vec(board, boards)).prop_map(|(draws, boards)| {
+        let mut text = join(&draws, ",");
+        text.push('\n');
+        for board in boards {
+            text.push('\n');
+            for row in board.chunks(5) {
+                let row: Vec<String> = row.iter().map(|n| format!("{:>2}", n)).collect();
+                text.push_str(&row.join(" "));
+                text.push('\n');
+            }
+        }
+        text
+    })
+}
+
+/// The comma separated timers of a lanternfish school (day 6), each
+/// between 0 and 8.
+pub fn lanternfish(fish: impl Into<SizeRange>) -> impl Strategy<Value = String> {
+    vec(0..=8u8, fish).prop_map(|timers| join(&timers, ",") + "\n")
+}
+
+/// Hydrothermal vent lines (day 5) inside a `size` x `size` square:
+/// horizontal, vertical or diagonal at 45 degrees, possibly a single point.
+pub fn vent_lines(size: i32, lines: impl Into<SizeRange>) -> impl Strategy<Value = String> {
+    let start = (0..size, 0..size);
+    let line = (start, 0..3, 0..size, any::<(bool, bool)>()).prop_map(
+        move |((x1, y1), kind, to, (right, down))| {
+            let (x2, y2) = match kind {
+                0 => (to, y1),
+                1 => (x1, to),
+                _ => {
+                    let room_x = if right { size - 1 - x1 } else { x1 };
+                    let room_y = if down { size - 1 - y1 } else { y1 };
+                    let length = to % (room_x.min(room_y) + 1);
+                    let step = |forward| if forward { length } else { -length };
+                    (x1 + step(right), y1 + step(down))
+                }
+            };
+            format!("{},{} -> {},{}", x1, y1, x2, y2)
+        },
+    );
+
+    vec(line, lines).prop_map(|lines| lines.join("\n") + "\n")
+}
+
+/// Segments lit for each digit, in the puzzle's unscrambled wiring.
+const SEGMENTS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+/// One note of the seven-segment search (day 8) with the value its four
+/// output digits display. The wires are renamed by a random permutation,
+/// the ten patterns come in random order, and so do the letters of every
+/// pattern.
+pub fn seven_segment() -> impl Strategy<Value = (String, u32)> {
+    let wires = Just("abcdefg".chars().collect::<Vec<char>>()).prop_shuffle();
+    let order = Just((0..10).collect::<Vec<usize>>()).prop_shuffle();
+    let letter_ranks = vec(Just((0..7).collect::<Vec<usize>>()).prop_shuffle(), 14);
+
+    (wires, order, vec(0..10usize, 4), letter_ranks).prop_map(|(wires, order, output, ranks)| {
+        let digits = order.iter().chain(output.iter());
+        let words: Vec<String> = digits
+            .zip(ranks)
+            .map(|(&digit, rank)| {
+                let mut segments: Vec<usize> = SEGMENTS[digit]
+                    .bytes()
+                    .map(|s| (s - b'a') as usize)
+                    .collect();
+                segments.sort_by_key(|&s| rank[s]);
+                segments.iter().map(|&s| wires[s]).collect()
+            })
+            .collect();
+
+        let value = output.iter().fold(0, |value, &d| value * 10 + d as u32);
+        let line = format!("{} | {}", words[..10].join(" "), words[10..].join(" "));
+        (line, value)
+    })
+}
+
+/// A heightmap (day 9) of random digits, between the given sizes.
+pub fn heightmap(width: Range<usize>, height: Range<usize>) -> impl Strategy<Value = String> {
+    (width, height)
+        .prop_flat_map(|(width, height)| vec(vec(0..=9u8, width), height))
+        .prop_map(|rows| {
+            rows.iter()
+                .map(|row| join(row, "") + "\n")
+                .collect::<String>()
+        })
+}
+
+/// A cave system (day 12): `start`, `end`, up to four small caves and two
+/// big ones, with `start` always connected. Two big caves are never
+/// connected to each other, an input day 12 rejects since it would allow
+/// infinitely many paths, and no edge is listed twice.
+pub fn cave_graph() -> impl Strategy<Value = String> {
+    const CAVES: [&str; 8] = ["start", "end", "dc", "kj", "sa", "tn", "HN", "LN"];
+    let is_big = |cave: usize| cave >= 6;
+
+    let edge = (0..CAVES.len(), 0..CAVES.len(), any::<bool>());
+    (1..CAVES.len(), any::<bool>(), vec(edge, 1..12)).prop_map(move |(first, flip, edges)| {
+        let mut seen = BTreeSet::new();
+        let edges = std::iter::once((0, first, flip))
+            .chain(edges)
+            .filter(|&(a, b, _)| a != b && !(is_big(a) && is_big(b)))
+            .filter(|&(a, b, _)| seen.insert((a.min(b), a.max(b))));
+
+        let mut text = String::new();
+        for (a, b, flip) in edges {
+            let (a, b) = if flip { (b, a) } else { (a, b) };
+            let _ = writeln!(text, "{}-{}", CAVES[a], CAVES[b]);
+        }
+        text
+    })
+}
+
+/// A polymer template and a complete set of pair insertion rules (day 14)
+/// over an alphabet of two to four elements.
+pub fn polymer() -> impl Strategy<Value = String> {
+    const ELEMENTS: [char; 4] = ['B', 'C', 'H', 'N'];
+
+    (2..=4usize)
+        .prop_flat_map(|size| (Just(size), vec(0..size, 2..8), vec(0..size, size * size)))
+        .prop_map(|(size, template, inserted)| {
+            let mut text: String = template.iter().map(|&e| ELEMENTS[e]).collect();
+            text.push_str("\n\n");
+            for (pair, &middle) in inserted.iter().enumerate() {
+                let _ = writeln!(
+                    text,
+                    "{}{} -> {}",
+                    ELEMENTS[pair / size],
+                    ELEMENTS[pair % size],
+                    ELEMENTS[middle]
+                );
+            }
+            text
+        })
+}
+
+/// A trench target area (day 17) to the right of and below the launch
+/// point, as in every puzzle input.
+pub fn target_area() -> impl Strategy<Value = String> {
+    (1..40i32, 0..15i32, -40..-1i32, 0..15i32).prop_map(|(min_x, width, max_y, height)| {
+        format!(
+            "target area: x={}..{}, y={}..{}\n",
+            min_x,
+            min_x + width,
+            max_y - height,
+            max_y
+        )
+    })
+}
+
+/// A BITS transmission (day 16) with the sum of its version numbers and the
+/// value of its expression, `None` when that overflows 64 bits.
+#[derive(Debug, Clone)]
+pub struct Transmission {
+    pub hex: String,
+    pub version_sum: u64,
+    pub value: Option<u64>,
+}
+
+#[derive(Debug, Clone)]
+enum Packet {
+    Literal {
+        version: u8,
+        value: u64,
+    },
+    Operator {
+        version: u8,
+        type_id: u8,
+        count_packets: bool,
+        packets: Vec<Packet>,
+    },
+}
+
+impl Packet {
+    fn encode(&self, bits: &mut String) {
+        match self {
+            Packet::Literal { version, value } => {
+                push_bits(bits, *version as u64, 3);
+                bits.push_str("100");
+                let groups = (64 - value.leading_zeros()).div_ceil(4).max(1);
+                for group in (0..groups).rev() {
+                    bits.push(if group == 0 { '0' } else { '1' });
+                    push_bits(bits, value >> (4 * group), 4);
+                }
+            }
+            Packet::Operator {
+                version,
+                type_id,
+                count_packets,
+                packets,
+            } => {
+                push_bits(bits, *version as u64, 3);
+                push_bits(bits, *type_id as u64, 3);
+                let mut inner = String::new();
+                for packet in packets {
+                    packet.encode(&mut inner);
+                }
+                if *count_packets {
+                    bits.push('1');
+                    push_bits(bits, packets.len() as u64, 11);
+                } else {
+                    bits.push('0');
+                    push_bits(bits, inner.len() as u64, 15);
+                }
+                bits.push_str(&inner);
+            }
+        }
+    }
+
+    fn version_sum(&self) -> u64 {
+        match self {
+            Packet::Literal { version, .. } => *version as u64,
+            Packet::Operator {
+                version, packets, ..
+            } => *version as u64 + packets.iter().map(Packet::version_sum).sum::<u64>(),
+        }
+    }
+
+    /// The value of the expression, `None` if it overflows.
+    fn value(&self) -> Option<u64> {
+        let (type_id, packets) = match self {
+            Packet::Literal { value, .. } => return Some(*value),
+            Packet::Operator {
+                type_id, packets, ..
+            } => (*type_id, packets),
+        };
+        let values = packets
+            .iter()
+            .map(Packet::value)
+            .collect::<Option<Vec<u64>>>()?;
+
+        match type_id {
+            0 => values.iter().try_fold(0u64, |acc, &v| acc.checked_add(v)),
+            1 => values.iter().try_fold(1u64, |acc, &v| acc.checked_mul(v)),
+            2 => values.iter().copied().min(),
+            3 => values.iter().copied().max(),
+            5 => Some((values[0] > values[1]) as u64),
+            6 => Some((values[0] < values[1]) as u64),
+            _ => Some((values[0] == values[1]) as u64),
+        }
+    }
+}
+
+fn push_bits(bits: &mut String, value: u64, width: u32) {
+    for shift in (0..width).rev() {
+        bits.push(if (value >> shift) & 1 == 1 { '1' } else { '0' });
+    }
+}
+
+/// A BITS transmission (day 16) of nested sums, products, minimums,
+/// maximums and comparisons, using both length types. Some of them have a
+/// value that does not fit in 64 bits.
+pub fn bits_transmission() -> impl Strategy<Value = Transmission> {
+    let literal = (0..8u8, prop_oneof![3 => 0..16u64, 1 => 0..1u64 << 36])
+        .prop_map(|(version, value)| Packet::Literal { version, value });
+
+    let packet = literal.prop_recursive(3, 24, 4, |inner| {
+        let operator = |type_id: Range<u8>, packets| {
+            (0..8u8, type_id, any::<bool>(), packets).prop_map(
+                |(version, type_id, count_packets, packets)| Packet::Operator {
+                    version,
+                    type_id,
+                    count_packets,
+                    packets,
+                },
+            )
+        };
+        prop_oneof![
+            operator(0..4, vec(inner.clone(), 1..4)),
+            operator(5..8, vec(inner, 2)),
+        ]
+    });
+
+    packet.prop_map(|packet| {
+        let mut bits = String::new();
+        packet.encode(&mut bits);
+        while !bits.len().is_multiple_of(8) {
+            bits.push('0');
+        }
+
+        let hex = bits
+            .as_bytes()
+            .chunks(4)
+            .map(|nibble| {
+                let nibble = std::str::from_utf8(nibble).unwrap();
+                format!("{:X}", u8::from_str_radix(nibble, 2).unwrap())
+            })
+            .collect();
+        Transmission {
+            hex,
+            version_sum: packet.version_sum(),
+            value: packet.value(),
+        }
+    })
+}
+
+fn join<T: ToString>(values: &[T], separator: &str) -> String {
+    values
+        .iter()
+        .map(T::to_string)
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::strategy::ValueTree;
+    use proptest::test_runner::TestRunner;
+
+    fn sample<S: Strategy>(strategy: S) -> S::Value {
+        let mut runner = TestRunner::deterministic();
+        strategy.new_tree(&mut runner).unwrap().current()
+    }
+
+    #[test]
+    fn test_encode_literal() {
+        let mut bits = String::new();
+        Packet::Literal {
+            version: 6,
+            value: 2021,
+        }
+        .encode(&mut bits);
+        assert_eq!("110100101111111000101", bits);
+    }
+
+    #[test]
+    fn test_encode_operator() {
+        let packet = Packet::Operator {
+            version: 1,
+            type_id: 6,
+            count_packets: false,
+            packets: vec![
+                Packet::Literal {
+                    version: 6,
+                    value: 10,
+                },
+                Packet::Literal {
+                    version: 2,
+                    value: 20,
+                },
+            ],
+        };
+        let mut bits = String::new();
+        packet.encode(&mut bits);
+        assert_eq!(
+            "00111000000000000110111101000101001010010001001000000000",
+            format!("{:0<56}", bits)
+        );
+        assert_eq!(9, packet.version_sum());
+        assert_eq!(Some(1), packet.value());
+    }
+
+    #[test]
+    fn test_shapes() {
+        let bingo = sample(bingo(3));
+        assert_eq!(1 + 3 * 6, bingo.lines().count());
+
+        let (line, value) = sample(seven_segment());
+        let (patterns, output) = line.split_once(" | ").unwrap();
+        assert_eq!(10, patterns.split(' ').count());
+        assert_eq!(4, output.split(' ').count());
+        assert!(value < 10_000);
+
+        let polymer = sample(polymer());
+        let rules = polymer.lines().skip(2).count();
+        assert!([4, 9, 16].contains(&rules));
+
+        let caves = sample(cave_graph());
+        assert!(caves
+            .lines()
+            .any(|edge| edge.split('-').any(|c| c == "start")));
+    }
+
+    proptest! {
+        #[test]
+        fn test_vent_lines_are_straight(text in vent_lines(10, 1..20)) {
+            for line in text.lines() {
+                let numbers: Vec<i32> = line
+                    .split([',', ' ', '-', '>'])
+                    .filter(|n| !n.is_empty())
+                    .map(|n| n.parse().unwrap())
+                    .collect();
+                let (dx, dy) = (numbers[2] - numbers[0], numbers[3] - numbers[1]);
+                prop_assert!(dx == 0 || dy == 0 || dx.abs() == dy.abs());
+                prop_assert!(numbers.iter().all(|n| (0..10).contains(n)));
+            }
+        }
+    }
+}
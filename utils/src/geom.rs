@@ -0,0 +1,360 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// A position on the plane. Subtracting two points gives the [`Vec2`]
+/// between them, and a point moves by adding a `Vec2` to it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A displacement, such as a velocity or the step between two points.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+}
+
+impl<T> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Vec2 { x, y }
+    }
+}
+
+impl<T> Point<T>
+where
+    T: Copy + PartialOrd + Sub<Output = T>,
+{
+    /// Number of orthogonal steps between the two points.
+    pub fn manhattan(self, other: Point<T>) -> T
+    where
+        T: Add<Output = T>,
+    {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// Number of king moves between the two points, diagonals included.
+    pub fn chebyshev(self, other: Point<T>) -> T {
+        max(abs_diff(self.x, other.x), abs_diff(self.y, other.y))
+    }
+}
+
+/// `|a - b|`, without going below zero for unsigned types.
+fn abs_diff<T: PartialOrd + Sub<Output = T>>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+fn min<T: PartialOrd>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+fn max<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+impl<T: Add<Output = T>> Add<Vec2<T>> for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, v: Vec2<T>) -> Point<T> {
+        Point::new(self.x + v.x, self.y + v.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign<Vec2<T>> for Point<T> {
+    fn add_assign(&mut self, v: Vec2<T>) {
+        self.x += v.x;
+        self.y += v.y;
+    }
+}
+
+impl<T: Sub<Output = T>> Sub<Vec2<T>> for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, v: Vec2<T>) -> Point<T> {
+        Point::new(self.x - v.x, self.y - v.y)
+    }
+}
+
+impl<T: SubAssign> SubAssign<Vec2<T>> for Point<T> {
+    fn sub_assign(&mut self, v: Vec2<T>) {
+        self.x -= v.x;
+        self.y -= v.y;
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Vec2<T>;
+
+    fn sub(self, other: Point<T>) -> Vec2<T> {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn add(self, other: Vec2<T>) -> Vec2<T> {
+        Vec2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Vec2<T> {
+    fn add_assign(&mut self, other: Vec2<T>) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn sub(self, other: Vec2<T>) -> Vec2<T> {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: SubAssign> SubAssign for Vec2<T> {
+    fn sub_assign(&mut self, other: Vec2<T>) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn mul(self, factor: T) -> Vec2<T> {
+        Vec2::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn neg(self) -> Vec2<T> {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(point: Point<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// A point that is not written as `x,y`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePointError {
+    message: String,
+}
+
+impl Display for ParsePointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for ParsePointError {}
+
+/// Parses `x,y`, as in `0,9 -> 5,9`. Spaces around each coordinate are
+/// ignored.
+impl<T> FromStr for Point<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    type Err = ParsePointError;
+
+    fn from_str(input: &str) -> Result<Self, ParsePointError> {
+        let error = |message: String| ParsePointError { message };
+
+        let (x, y) = input
+            .split_once(',')
+            .ok_or_else(|| error(format!("expected x,y, found {:?}", input)))?;
+        let x = x
+            .trim()
+            .parse::<T>()
+            .map_err(|e| error(format!("x {:?}: {}", x.trim(), e)))?;
+        let y = y
+            .trim()
+            .parse::<T>()
+            .map_err(|e| error(format!("y {:?}: {}", y.trim(), e)))?;
+
+        Ok(Point::new(x, y))
+    }
+}
+
+/// An axis-aligned rectangle; both corners are inside it, as with the
+/// `x=20..30, y=-10..-5` ranges of the puzzles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect<T> {
+    pub min: Point<T>,
+    pub max: Point<T>,
+}
+
+impl<T: Copy + PartialOrd> Rect<T> {
+    /// The rectangle with `a` and `b` as opposite corners, in any order.
+    pub fn new(a: Point<T>, b: Point<T>) -> Self {
+        Rect {
+            min: Point::new(min(a.x, b.x), min(a.y, b.y)),
+            max: Point::new(max(a.x, b.x), max(a.y, b.y)),
+        }
+    }
+
+    /// Smallest rectangle holding every point, `None` if there are none.
+    pub fn bounding<I>(points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Point<T>>,
+    {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Rect::new(first, first), |rect, p| Rect {
+            min: Point::new(min(rect.min.x, p.x), min(rect.min.y, p.y)),
+            max: Point::new(max(rect.max.x, p.x), max(rect.max.y, p.y)),
+        }))
+    }
+
+    pub fn contains(&self, point: Point<T>) -> bool {
+        self.min.x <= point.x
+            && point.x <= self.max.x
+            && self.min.y <= point.y
+            && point.y <= self.max.y
+    }
+
+    /// The area shared by both rectangles, `None` if they do not overlap.
+    pub fn intersect(&self, other: &Rect<T>) -> Option<Rect<T>> {
+        let min = Point::new(max(self.min.x, other.min.x), max(self.min.y, other.min.y));
+        let max = Point::new(
+            self::min(self.max.x, other.max.x),
+            self::min(self.max.y, other.max.y),
+        );
+
+        if min.x <= max.x && min.y <= max.y {
+            Some(Rect { min, max })
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let mut p = Point::new(1, 2);
+        let v = Vec2::new(3, -1);
+
+        assert_eq!(Point::new(4, 1), p + v);
+        assert_eq!(Point::new(-2, 3), p - v);
+        assert_eq!(v, (p + v) - p);
+        assert_eq!(Vec2::new(6, -2), v * 2);
+        assert_eq!(Vec2::new(-3, 1), -v);
+        assert_eq!(Vec2::new(4, 0), v + Vec2::new(1, 1));
+
+        p += v;
+        p += v;
+        assert_eq!(Point::new(7, 0), p);
+        p -= v;
+        assert_eq!(Point::new(4, 1), p);
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new(1, -2);
+        let b = Point::new(-3, 4);
+        assert_eq!(10, a.manhattan(b));
+        assert_eq!(6, a.chebyshev(b));
+        assert_eq!(a.manhattan(b), b.manhattan(a));
+
+        let a: Point<usize> = Point::new(0, 7);
+        let b: Point<usize> = Point::new(9, 4);
+        assert_eq!(12, a.manhattan(b));
+        assert_eq!(9, b.chebyshev(a));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Ok(Point::new(0, 9)), "0,9".parse());
+        assert_eq!(Ok(Point::new(-12, 3)), " -12 , 3 ".parse());
+        assert_eq!("0,9", Point::new(0, 9).to_string());
+
+        assert!("0;9".parse::<Point<i32>>().is_err());
+        assert!("0,".parse::<Point<i32>>().is_err());
+        assert!("-1,2".parse::<Point<usize>>().is_err());
+    }
+
+    #[test]
+    fn test_rect() {
+        let target = Rect::new(Point::new(30, -5), Point::new(20, -10));
+        assert_eq!(Point::new(20, -10), target.min);
+        assert_eq!(Point::new(30, -5), target.max);
+
+        assert!(target.contains(Point::new(20, -10)));
+        assert!(target.contains(Point::new(28, -7)));
+        assert!(!target.contains(Point::new(31, -7)));
+        assert!(!target.contains(Point::new(25, -4)));
+    }
+
+    #[test]
+    fn test_intersect() {
+        let a = Rect::new(Point::new(0, 0), Point::new(4, 4));
+        let b = Rect::new(Point::new(3, 2), Point::new(8, 9));
+        let c = Rect::new(Point::new(5, 0), Point::new(6, 1));
+
+        assert_eq!(
+            Some(Rect::new(Point::new(3, 2), Point::new(4, 4))),
+            a.intersect(&b)
+        );
+        assert_eq!(a.intersect(&b), b.intersect(&a));
+        assert_eq!(None, a.intersect(&c));
+        assert_eq!(
+            Some(Rect::new(Point::new(4, 4), Point::new(4, 4))),
+            a.intersect(&Rect::new(Point::new(4, 4), Point::new(7, 7)))
+        );
+    }
+
+    #[test]
+    fn test_bounding() {
+        let points = vec![Point::new(3, 1), Point::new(-1, 4), Point::new(2, -2)];
+        assert_eq!(
+            Some(Rect::new(Point::new(-1, -2), Point::new(3, 4))),
+            Rect::bounding(points)
+        );
+        assert_eq!(None, Rect::<i32>::bounding(vec![]));
+    }
+}
@@ -0,0 +1,367 @@
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use crate::geom::Point;
+use crate::input::{parse_grid, InputError};
+
+const DIRECTIONS4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const DIRECTIONS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A dense rectangular grid, stored row by row. Cells are addressed by
+/// `(x, y)`: `x` is the column, growing to the right, and `y` the row,
+/// growing downwards, as in the puzzle texts.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` x `height` grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(width, height, |_, _| fill.clone())
+    }
+
+    /// A `width` x `height` grid where each cell is `cell(x, y)`.
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(usize, usize) -> T) -> Self {
+        let (width, height) = if width == 0 || height == 0 {
+            (0, 0)
+        } else {
+            (width, height)
+        };
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(cell(x, y));
+            }
+        }
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Builds a grid from its rows, or `None` if they do not all have the
+    /// same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        let height = if width == 0 { 0 } else { rows.len() };
+        Some(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if self.contains(x, y) {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.contains(x, y) {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The up to 4 orthogonal neighbours of `(x, y)` inside the grid.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(x, y, &DIRECTIONS4)
+    }
+
+    /// The up to 8 orthogonal and diagonal neighbours of `(x, y)` inside the
+    /// grid.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(x, y, &DIRECTIONS8)
+    }
+
+    fn offsets(
+        &self,
+        x: usize,
+        y: usize,
+        directions: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        directions.iter().filter_map(move |&(dx, dy)| {
+            let x = x.checked_add_signed(dx)?;
+            let y = y.checked_add_signed(dy)?;
+            self.contains(x, y).then_some((x, y))
+        })
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        if y < self.height {
+            Some(&self.cells[y * self.width..(y + 1) * self.width])
+        } else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of column `x`, top to bottom; empty if `x` is out of the
+    /// grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let start = if x < self.width { x } else { self.cells.len() };
+        self.cells[start..].iter().step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(&mut f).collect(),
+        }
+    }
+
+    /// Swaps rows and columns: the cell at `(x, y)` moves to `(y, x)`.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |x, y| self[(y, x)].clone())
+    }
+
+    /// Repeats the grid `across` times to the right and `down` times
+    /// downwards. Each cell of the copy at tile `(tile_x, tile_y)` is
+    /// `cell(value, tile_x, tile_y)`.
+    pub fn tile<U>(
+        &self,
+        across: usize,
+        down: usize,
+        mut cell: impl FnMut(&T, usize, usize) -> U,
+    ) -> Grid<U> {
+        Grid::from_fn(self.width * across, self.height * down, |x, y| {
+            cell(
+                &self[(x % self.width, y % self.height)],
+                x / self.width,
+                y / self.height,
+            )
+        })
+    }
+
+    /// Draws the grid one character per cell, one line per row.
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        let mut output = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            output.extend(row.iter().map(&mut cell));
+            output.push('\n');
+        }
+        output
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        match self.get(x, y) {
+            Some(cell) => cell,
+            None => panic!(
+                "({}, {}) is outside of a {}x{} grid",
+                x, y, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(x, y) {
+            Some(cell) => cell,
+            None => panic!("({}, {}) is outside of a {}x{} grid", x, y, width, height),
+        }
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point<usize>) -> &T {
+        &self[(point.x, point.y)]
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: Point<usize>) -> &mut T {
+        &mut self[(point.x, point.y)]
+    }
+}
+
+/// Parses one character per cell, e.g. a block of digits.
+impl<T> FromStr for Grid<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    type Err = InputError;
+
+    fn from_str(input: &str) -> Result<Self, InputError> {
+        let rows = parse_grid(input)?;
+        Ok(Grid::from_rows(rows).expect("parse_grid rejects ragged rows"))
+    }
+}
+
+/// Writes every cell with its `Display`, one line per row, so that a digit
+/// grid renders back to the text it was parsed from.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<u8> {
+        "123\n456\n".parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_render() {
+        let grid = sample();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(6, grid[(2, 1)]);
+        assert_eq!("123\n456\n", grid.to_string());
+        assert_eq!(
+            "..#\n###\n",
+            grid.render(|&n| if n > 2 { '#' } else { '.' })
+        );
+
+        assert!(matches!(
+            "12\n3x\n".parse::<Grid<u8>>(),
+            Err(InputError::Parse {
+                line: 2,
+                column: 2,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid = sample();
+        assert_eq!(Some(&4), grid.get(0, 1));
+        assert_eq!(None, grid.get(3, 0));
+        assert_eq!(None, grid.get(0, 2));
+
+        *grid.get_mut(1, 0).unwrap() = 9;
+        grid[(2, 1)] = 0;
+        assert_eq!("193\n450\n", grid.to_string());
+        assert_eq!(5, grid[Point::new(1, 1)]);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid: Grid<u8> = Grid::new(3, 3, 0);
+
+        let corner: Vec<_> = grid.neighbours4(0, 0).collect();
+        assert_eq!(vec![(1, 0), (0, 1)], corner);
+        assert_eq!(4, grid.neighbours4(1, 1).count());
+
+        assert_eq!(3, grid.neighbours8(2, 2).count());
+        assert_eq!(5, grid.neighbours8(1, 0).count());
+        assert_eq!(8, grid.neighbours8(1, 1).count());
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = sample();
+
+        assert_eq!(Some(&[4, 5, 6][..]), grid.row(1));
+        assert_eq!(None, grid.row(2));
+        assert_eq!(
+            vec![&[1, 2, 3][..], &[4, 5, 6][..]],
+            grid.rows().collect::<Vec<_>>()
+        );
+
+        assert_eq!(vec![&2, &5], grid.column(1).collect::<Vec<_>>());
+        assert_eq!(0, grid.column(3).count());
+        let columns: Vec<Vec<u8>> = grid.columns().map(|c| c.copied().collect()).collect();
+        assert_eq!(vec![vec![1, 4], vec![2, 5], vec![3, 6]], columns);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let grid = sample().transpose();
+        assert_eq!((2, 3), (grid.width(), grid.height()));
+        assert_eq!("14\n25\n36\n", grid.to_string());
+        assert_eq!(sample(), grid.transpose());
+    }
+
+    #[test]
+    fn test_tile() {
+        let grid: Grid<u8> = "89\n".parse().unwrap();
+        let tiled = grid.tile(2, 2, |&n, x, y| (n as usize + x + y - 1) % 9 + 1);
+        assert_eq!("8991\n9112\n", tiled.to_string());
+    }
+
+    #[test]
+    fn test_from_rows() {
+        assert_eq!(None, Grid::from_rows(vec![vec![1, 2], vec![3]]));
+
+        let empty: Grid<u8> = Grid::from_rows(vec![vec![], vec![]]).unwrap();
+        assert_eq!((0, 0), (empty.width(), empty.height()));
+        assert_eq!("", empty.to_string());
+        assert_eq!(0, empty.positions().count());
+    }
+}
@@ -0,0 +1,310 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Everything that can go wrong between opening a puzzle input and getting
+/// typed values out of it. Line and column numbers are 1-based.
+#[derive(Debug)]
+pub enum InputError {
+    /// The input file does not exist.
+    Missing(PathBuf),
+    /// Reading failed; `line` is 0 when the file could not be opened at all.
+    Io { line: usize, source: io::Error },
+    /// The line is not valid UTF-8.
+    Decode { line: usize },
+    /// The text was read but does not have the expected shape.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+}
+
+impl InputError {
+    pub fn parse<M: Display>(line: usize, column: usize, message: M) -> Self {
+        InputError::Parse {
+            line,
+            column,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Missing(path) => write!(f, "input file {} not found", path.display()),
+            InputError::Io { line: 0, source } => write!(f, "cannot open input: {}", source),
+            InputError::Io { line, source } => write!(f, "I/O error on line {}: {}", line, source),
+            InputError::Decode { line } => write!(f, "line {} is not valid UTF-8", line),
+            InputError::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "line {}, column {}: {}", line, column, message),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// The command line argument that stands for standard input.
+pub const STDIN: &str = "-";
+
+/// Reads a whole input file, keeping track of the line being read so that
+/// I/O and decoding failures can be located.
+pub fn read_input<P>(filename: P) -> Result<String, InputError>
+where
+    P: AsRef<Path>,
+{
+    let path = filename.as_ref();
+    let file = File::open(path).map_err(|source| match source.kind() {
+        io::ErrorKind::NotFound => InputError::Missing(path.to_path_buf()),
+        _ => InputError::Io { line: 0, source },
+    })?;
+
+    read_from(file)
+}
+
+/// Like [`read_input`], but [`STDIN`] reads standard input instead of a file.
+pub fn read_source<P>(source: P) -> Result<String, InputError>
+where
+    P: AsRef<Path>,
+{
+    if source.as_ref() == Path::new(STDIN) {
+        read_from(io::stdin().lock())
+    } else {
+        read_input(source)
+    }
+}
+
+/// Reads everything `reader` yields, with the same error reporting as
+/// [`read_input`].
+pub fn read_from<R: Read>(reader: R) -> Result<String, InputError> {
+    let mut reader = BufReader::new(reader);
+    let mut result = String::new();
+    let mut buffer: Vec<u8> = Vec::new();
+    let mut line = 0;
+
+    loop {
+        line += 1;
+        buffer.clear();
+        match reader.read_until(b'\n', &mut buffer) {
+            Ok(0) => break,
+            Ok(_) => match std::str::from_utf8(&buffer) {
+                Ok(text) => result.push_str(text),
+                Err(_) => return Err(InputError::Decode { line }),
+            },
+            Err(source) => return Err(InputError::Io { line, source }),
+        }
+    }
+
+    Ok(result)
+}
+
+pub fn read_lines<P>(filename: P) -> Result<Vec<String>, InputError>
+where
+    P: AsRef<Path>,
+{
+    Ok(read_input(filename)?.lines().map(String::from).collect())
+}
+
+/// Parses every non-blank line of `input` as a `T`.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, InputError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            let trimmed = line.trim_start();
+            let column = line.chars().count() - trimmed.chars().count() + 1;
+            trimmed
+                .trim_end()
+                .parse::<T>()
+                .map_err(|e| InputError::parse(index + 1, column, e))
+        })
+        .collect()
+}
+
+/// Parses a single comma separated line such as `3,4,3,1,2`. Errors are
+/// reported on line 1, with the character column where the offending field
+/// starts, after any padding.
+pub fn parse_csv_line<T>(line: &str) -> Result<Vec<T>, InputError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let line = line.trim_end();
+    let mut column = 1;
+    let mut result = Vec::new();
+
+    for field in line.split(',') {
+        let trimmed = field.trim();
+        let padding = field.chars().take_while(|c| c.is_whitespace()).count();
+        let value = trimmed
+            .parse::<T>()
+            .map_err(|e| InputError::parse(1, column + padding, format!("{:?}: {}", trimmed, e)))?;
+        result.push(value);
+        column += field.chars().count() + 1;
+    }
+
+    Ok(result)
+}
+
+/// Parses a rectangular block of single-character cells, one row per line.
+/// Blank lines are skipped and every row must have the width of the first.
+pub fn parse_grid<T>(input: &str) -> Result<Vec<Vec<T>>, InputError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let mut result: Vec<Vec<T>> = Vec::new();
+
+    for (index, line) in input.lines().enumerate() {
+        let line = line.trim_end();
+        if line.is_empty() {
+            continue;
+        }
+
+        let mut row = Vec::with_capacity(line.len());
+        for (column, cell) in line.chars().enumerate() {
+            let mut buffer = [0; 4];
+            let value = cell.encode_utf8(&mut buffer).parse::<T>().map_err(|e| {
+                InputError::parse(index + 1, column + 1, format!("{:?}: {}", cell, e))
+            })?;
+            row.push(value);
+        }
+
+        if let Some(first) = result.first() {
+            if first.len() != row.len() {
+                return Err(InputError::parse(
+                    index + 1,
+                    row.len().min(first.len()) + 1,
+                    format!("expected {} columns, found {}", first.len(), row.len()),
+                ));
+            }
+        }
+        result.push(row);
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn temp_file(name: &str, content: &[u8]) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("utils-input-{}-{}", std::process::id(), name));
+        File::create(&path).unwrap().write_all(content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_missing_file() {
+        match read_input("./does-not-exist.txt") {
+            Err(InputError::Missing(path)) => {
+                assert_eq!(PathBuf::from("./does-not-exist.txt"), path)
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_decode_error_line() {
+        let path = temp_file("decode", b"1\n2\n\xff\xfe\n4\n");
+        match read_lines(&path) {
+            Err(InputError::Decode { line }) => assert_eq!(3, line),
+            other => panic!("unexpected {:?}", other),
+        }
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_read_from() {
+        assert_eq!("3,4,3\n", read_from(&b"3,4,3\n"[..]).unwrap());
+        assert!(matches!(
+            read_from(&b"ok\n\xff\n"[..]),
+            Err(InputError::Decode { line: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_read_source_path() {
+        let path = temp_file("source", b"16,1,2\n");
+        assert_eq!("16,1,2\n", read_source(&path).unwrap());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_read_lines() {
+        let path = temp_file("lines", b"199\n200\n208\n");
+        assert_eq!(vec!["199", "200", "208"], read_lines(&path).unwrap());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let values: Vec<i32> = parse_lines("199\n200\n\n-3\n").unwrap();
+        assert_eq!(vec![199, 200, -3], values);
+
+        match parse_lines::<i32>("1\n  2x\n") {
+            Err(InputError::Parse { line, column, .. }) => assert_eq!((2, 3), (line, column)),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_csv_line() {
+        let values: Vec<usize> = parse_csv_line("3,4,3,1,2\n").unwrap();
+        assert_eq!(vec![3, 4, 3, 1, 2], values);
+
+        match parse_csv_line::<usize>("3,4,x,1") {
+            Err(InputError::Parse { line, column, .. }) => assert_eq!((1, 5), (line, column)),
+            other => panic!("unexpected {:?}", other),
+        }
+
+        // Columns count characters and skip the padding before a field.
+        assert_eq!(vec![3, 4], parse_csv_line::<usize>("3, 4 \n").unwrap());
+        match parse_csv_line::<usize>("3,  x") {
+            Err(InputError::Parse { column, .. }) => assert_eq!(5, column),
+            other => panic!("unexpected {:?}", other),
+        }
+        match parse_csv_line::<char>("é,xy") {
+            Err(InputError::Parse { column, .. }) => assert_eq!(3, column),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_grid() {
+        let grid: Vec<Vec<u8>> = parse_grid("219\n398\n").unwrap();
+        assert_eq!(vec![vec![2, 1, 9], vec![3, 9, 8]], grid);
+
+        match parse_grid::<u8>("219\n3a8\n") {
+            Err(InputError::Parse { line, column, .. }) => assert_eq!((2, 2), (line, column)),
+            other => panic!("unexpected {:?}", other),
+        }
+
+        assert!(matches!(
+            parse_grid::<u8>("219\n39\n"),
+            Err(InputError::Parse { line: 2, .. })
+        ));
+    }
+}
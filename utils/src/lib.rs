@@ -1,17 +1,18 @@
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
+#[cfg(feature = "fetch")]
+pub mod fetch;
+#[cfg(feature = "gen")]
+pub mod gen;
+pub mod geom;
+pub mod grid;
+pub mod input;
+pub mod solution;
+#[cfg(feature = "fetch")]
+pub mod submit;
 
-pub fn read_lines<P>(filename: P) -> io::Result<Vec<String>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file)
-        .lines()
-        .map(|l| l.unwrap())
-        .collect())
-}
+pub use geom::{Point, Rect, Vec2};
+pub use grid::Grid;
+pub use input::{read_lines, InputError};
+pub use solution::{Answer, Solution, SolveError};
 
 #[cfg(test)]
 mod tests {
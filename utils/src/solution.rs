@@ -0,0 +1,196 @@
+use std::error::Error;
+use std::ffi::OsString;
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+
+use crate::input::{read_source, InputError};
+
+/// A day of the calendar: how to read its input and how to answer both parts.
+pub trait Solution {
+    /// Day of the month, from 1 to 25.
+    const DAY: u8;
+
+    /// Typed model of the puzzle input, shared by both parts.
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError>;
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError>;
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError>;
+}
+
+/// A puzzle answer: most days produce a number, a few draw their answer as
+/// ASCII art.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+}
+
+macro_rules! answer_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(value: $int) -> Self {
+                    Answer::Int(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(i32, i64, i128, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text.trim_end()),
+        }
+    }
+}
+
+/// The input parsed but a part could not be answered from it, e.g. a
+/// bingo where no board ever wins.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    message: String,
+}
+
+impl SolveError {
+    pub fn new<M: Display>(message: M) -> Self {
+        SolveError {
+            message: message.to_string(),
+        }
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for SolveError {}
+
+/// Parses `text` and answers both parts.
+pub fn solve<S: Solution>(text: &str) -> Result<[Answer; 2], Box<dyn Error>> {
+    let input = S::parse(text)?;
+    Ok([S::part1(&input)?, S::part2(&input)?])
+}
+
+/// Entry point shared by the `dayN` binaries: solves the file given as
+/// first argument, standard input for `-`, or `default` without argument.
+/// Prints both answers, or the error and a non-zero exit code.
+pub fn run_main<S: Solution>(default: impl AsRef<Path>) {
+    let path = match input_arg(std::env::args_os().skip(1), default.as_ref()) {
+        Some(path) => path,
+        None => {
+            eprintln!("usage: day{} [INPUT | -]", S::DAY);
+            std::process::exit(2);
+        }
+    };
+
+    match read_source(path)
+        .map_err(Box::<dyn Error>::from)
+        .and_then(|text| solve::<S>(&text))
+    {
+        Ok(answers) => {
+            for (part, answer) in answers.iter().enumerate() {
+                match answer {
+                    Answer::Int(_) => println!("Part {} : {}", part + 1, answer),
+                    Answer::Text(_) => println!("Part {} :\n{}", part + 1, answer),
+                }
+            }
+        }
+        Err(error) => {
+            eprintln!("day {}: {}", S::DAY, error);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// The input named on the command line, or `default`; `None` when there
+/// are too many arguments.
+fn input_arg(mut args: impl Iterator<Item = OsString>, default: &Path) -> Option<PathBuf> {
+    let path = args
+        .next()
+        .map_or_else(|| default.to_path_buf(), PathBuf::from);
+    match args.next() {
+        Some(_) => None,
+        None => Some(path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+        type Input = Vec<i32>;
+
+        fn parse(input: &str) -> Result<Self::Input, InputError> {
+            crate::input::parse_lines(input)
+        }
+
+        fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+            Ok(input.iter().sum::<i32>().into())
+        }
+
+        fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+            input
+                .iter()
+                .max()
+                .map(|max| Answer::from(*max))
+                .ok_or_else(|| SolveError::new("empty input"))
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(
+            [Answer::Int(6), Answer::Int(3)],
+            solve::<Sum>("1\n2\n3\n").unwrap()
+        );
+        assert!(solve::<Sum>("1\nx\n").is_err());
+        assert_eq!("empty input", solve::<Sum>("").unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!("42", Answer::from(42_u64).to_string());
+        assert_eq!(
+            "#.#\n.#.",
+            Answer::from("#.#\n.#.\n".to_string()).to_string()
+        );
+    }
+
+    #[test]
+    fn test_input_arg() {
+        let default = Path::new("/repo/day1/input.txt");
+        let args = |list: &[&str]| {
+            list.iter()
+                .map(OsString::from)
+                .collect::<Vec<_>>()
+                .into_iter()
+        };
+
+        assert_eq!(Some(default.to_path_buf()), input_arg(args(&[]), default));
+        assert_eq!(Some(PathBuf::from("-")), input_arg(args(&["-"]), default));
+        assert_eq!(
+            Some(PathBuf::from("gen/big.txt")),
+            input_arg(args(&["gen/big.txt"]), default)
+        );
+        assert_eq!(None, input_arg(args(&["a", "b"]), default));
+    }
+}
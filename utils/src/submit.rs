@@ -0,0 +1,524 @@
+//! Posts answers to adventofcode.com and remembers every verdict, so that an
+//! answer known to be wrong is never sent twice.
+
+use std::fmt::{self, Display};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::fetch::{Client, FetchError, YEAR};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Right,
+    Wrong(Option<Hint>),
+}
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    /// Wrong, with the delay imposed before the next try.
+    Wrong {
+        hint: Option<Hint>,
+        wait: Option<Duration>,
+    },
+    /// The previous answer was too recent; nothing was checked.
+    TooSoon {
+        wait: Option<Duration>,
+    },
+    /// This part was already solved, or is not unlocked yet.
+    AlreadySolved,
+    /// A page the parser does not recognise, as text.
+    Unknown(String),
+}
+
+/// Reads the verdict out of the HTML page returned for an answer.
+pub fn parse_verdict(html: &str) -> Verdict {
+    let text = article_text(html);
+
+    if text.contains("That's the right answer") {
+        Verdict::Right
+    } else if text.contains("That's not the right answer") {
+        let hint = if text.contains("too high") {
+            Some(Hint::TooHigh)
+        } else if text.contains("too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        Verdict::Wrong {
+            hint,
+            wait: parse_wait(&text),
+        }
+    } else if text.contains("You gave an answer too recently") {
+        Verdict::TooSoon {
+            wait: parse_wait(&text),
+        }
+    } else if text.contains("You don't seem to be solving the right level") {
+        Verdict::AlreadySolved
+    } else {
+        Verdict::Unknown(text)
+    }
+}
+
+/// The text of the page's `<article>`, without tags and with whitespace
+/// collapsed.
+fn article_text(html: &str) -> String {
+    let article = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Understands "You have 4m 32s left to wait" and "please wait one minute"
+/// or "wait 5 minutes".
+fn parse_wait(text: &str) -> Option<Duration> {
+    if let Some(start) = text.find("You have ") {
+        let rest = &text[start + "You have ".len()..];
+        let end = rest.find(" left to wait")?;
+        let mut seconds: u64 = 0;
+        for token in rest[..end].split_whitespace() {
+            let (at, _) = token.char_indices().last()?;
+            let (value, unit) = token.split_at(at);
+            let value: u64 = value.parse().ok()?;
+            let value = match unit {
+                "h" => value.checked_mul(3600)?,
+                "m" => value.checked_mul(60)?,
+                "s" => value,
+                _ => return None,
+            };
+            seconds = seconds.checked_add(value)?;
+        }
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let start = text.find("wait ")?;
+    let mut words = text[start + "wait ".len()..].split_whitespace();
+    let count: u64 = match words.next()? {
+        "one" | "a" => 1,
+        "five" => 5,
+        "ten" => 10,
+        number => number.parse().ok()?,
+    };
+    match words.next()? {
+        unit if unit.starts_with("minute") => Some(Duration::from_secs(count.checked_mul(60)?)),
+        unit if unit.starts_with("second") => Some(Duration::from_secs(count)),
+        _ => None,
+    }
+}
+
+/// One verdict remembered for a day and part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub day: u8,
+    pub part: u8,
+    pub outcome: Outcome,
+    pub answer: String,
+}
+
+impl Entry {
+    /// Whether this verdict already tells how `answer` would be judged: it
+    /// is the same answer, the part is already solved, or the answer is past
+    /// a "too high" or "too low" bound.
+    fn decides(&self, answer: &str) -> bool {
+        let numbers = (self.answer.parse::<i128>(), answer.parse::<i128>());
+        match (self.outcome, numbers) {
+            (Outcome::Right, _) => true,
+            (Outcome::Wrong(Some(Hint::TooHigh)), (Ok(bound), Ok(value))) => value >= bound,
+            (Outcome::Wrong(Some(Hint::TooLow)), (Ok(bound), Ok(value))) => value <= bound,
+            _ => self.answer == answer,
+        }
+    }
+}
+
+impl Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let outcome = match self.outcome {
+            Outcome::Right => "right",
+            Outcome::Wrong(None) => "wrong",
+            Outcome::Wrong(Some(Hint::TooHigh)) => "too-high",
+            Outcome::Wrong(Some(Hint::TooLow)) => "too-low",
+        };
+        write!(f, "{} {} {} {}", self.day, self.part, outcome, self.answer)
+    }
+}
+
+fn parse_entry(line: &str) -> Option<Entry> {
+    let mut fields = line.splitn(4, ' ');
+    let day = fields.next()?.parse().ok()?;
+    let part = fields.next()?.parse().ok()?;
+    let outcome = match fields.next()? {
+        "right" => Outcome::Right,
+        "wrong" => Outcome::Wrong(None),
+        "too-high" => Outcome::Wrong(Some(Hint::TooHigh)),
+        "too-low" => Outcome::Wrong(Some(Hint::TooLow)),
+        _ => return None,
+    };
+    let answer = fields.next()?.to_string();
+    Some(Entry {
+        day,
+        part,
+        outcome,
+        answer,
+    })
+}
+
+/// Every verdict received so far, stored one per line as
+/// `day part outcome answer`.
+#[derive(Debug)]
+pub struct History {
+    path: PathBuf,
+    entries: Vec<Entry>,
+}
+
+impl History {
+    /// The history kept next to the client's cached inputs for [`YEAR`], so
+    /// it outlives the session cookie.
+    pub fn for_client(client: &Client) -> Result<Self, FetchError> {
+        History::load(client.year_dir().join("answers.txt"))
+    }
+
+    /// Loads the history stored at `path`; a missing file is an empty
+    /// history.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, FetchError> {
+        let path = path.into();
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(source) => return Err(FetchError::Io { path, source }),
+        };
+
+        let mut entries = Vec::new();
+        for (index, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            match parse_entry(line) {
+                Some(entry) => entries.push(entry),
+                None => {
+                    let message = format!("line {} is not a verdict: {:?}", index + 1, line);
+                    return Err(FetchError::Io {
+                        path,
+                        source: io::Error::new(io::ErrorKind::InvalidData, message),
+                    });
+                }
+            }
+        }
+
+        Ok(History { path, entries })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn entries(&self, day: u8, part: u8) -> impl Iterator<Item = &Entry> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.day == day && entry.part == part)
+    }
+
+    /// A past verdict that already decides `answer`, if any.
+    pub fn known(&self, day: u8, part: u8, answer: &str) -> Option<&Entry> {
+        self.entries(day, part).find(|entry| entry.decides(answer))
+    }
+
+    pub fn record(&mut self, entry: Entry) -> Result<(), FetchError> {
+        check_answer(&entry.answer)?;
+        let io_error = |source| FetchError::Io {
+            path: self.path.clone(),
+            source,
+        };
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).map_err(io_error)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(io_error)?;
+        writeln!(file, "{}", entry).map_err(io_error)?;
+
+        self.entries.push(entry);
+        Ok(())
+    }
+}
+
+/// An answer is a single word on a line of the history file.
+fn check_answer(answer: &str) -> Result<(), FetchError> {
+    if answer.is_empty() || answer.contains(char::is_whitespace) {
+        return Err(FetchError::BadAnswer(answer.to_string()));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Submission {
+    /// Not sent: the history already holds this verdict.
+    Known(Entry),
+    Sent(Verdict),
+}
+
+/// Submits `answer` unless the history already decides it, and records the
+/// verdict when the site gives one.
+pub fn submit(
+    client: &Client,
+    history: &mut History,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Submission, FetchError> {
+    check_answer(answer)?;
+    if let Some(entry) = history.known(day, part, answer) {
+        return Ok(Submission::Known(entry.clone()));
+    }
+
+    let path = format!("/{}/day/{}/answer", YEAR, day);
+    let level = part.to_string();
+    let page = client.post_form(&path, &[("level", &level), ("answer", answer)])?;
+    let verdict = parse_verdict(&page);
+
+    let outcome = match verdict {
+        Verdict::Right => Some(Outcome::Right),
+        Verdict::Wrong { hint, .. } => Some(Outcome::Wrong(hint)),
+        _ => None,
+    };
+    if let Some(outcome) = outcome {
+        history.record(Entry {
+            day,
+            part,
+            outcome,
+            answer: answer.to_string(),
+        })?;
+    }
+
+    Ok(Submission::Sent(verdict))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetch::tests::{mock_server, temp_dir};
+    use std::sync::atomic::Ordering;
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            message
+        )
+    }
+
+    #[test]
+    fn test_parse_verdict() {
+        assert_eq!(
+            Verdict::Right,
+            parse_verdict(&page(
+                "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer."
+            ))
+        );
+        assert_eq!(
+            Verdict::Wrong {
+                hint: Some(Hint::TooHigh),
+                wait: Some(Duration::from_secs(60))
+            },
+            parse_verdict(&page(
+                "That's not the right answer; your answer is too high.  If you're stuck, \
+                 make sure you're using the full input data.  Please wait one minute \
+                 before trying again. <a href=\"/2021/day/7\">[Return to Day 7]</a>"
+            ))
+        );
+        assert_eq!(
+            Verdict::Wrong {
+                hint: Some(Hint::TooLow),
+                wait: Some(Duration::from_secs(300))
+            },
+            parse_verdict(&page(
+                "That's not the right answer; your answer is too low.  \
+                 Please wait 5 minutes before trying again."
+            ))
+        );
+        assert_eq!(
+            Verdict::Wrong {
+                hint: None,
+                wait: None
+            },
+            parse_verdict(&page("That's not the right answer."))
+        );
+        assert_eq!(
+            Verdict::TooSoon {
+                wait: Some(Duration::from_secs(272))
+            },
+            parse_verdict(&page(
+                "You gave an answer too recently; you have to wait after submitting an \
+                 answer before trying again.  You have 4m 32s left to wait."
+            ))
+        );
+        assert_eq!(
+            Verdict::AlreadySolved,
+            parse_verdict(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ))
+        );
+        assert!(matches!(
+            parse_verdict("<html>Puzzle inputs differ by user.</html>"),
+            Verdict::Unknown(_)
+        ));
+    }
+
+    #[test]
+    fn test_parse_wait() {
+        assert_eq!(
+            Some(Duration::from_secs(3723)),
+            parse_wait("You have 1h 2m 3s left to wait.")
+        );
+        assert_eq!(None, parse_wait("You have 4é left to wait."));
+        assert_eq!(None, parse_wait("You have 4mé left to wait."));
+        assert_eq!(None, parse_wait("You have s left to wait."));
+        assert_eq!(None, parse_wait("You have 1x left to wait."));
+        assert_eq!(
+            None,
+            parse_wait("You have 9999999999999999999h left to wait.")
+        );
+        assert_eq!(
+            None,
+            parse_wait("You have 18446744073709551615s 1s left to wait.")
+        );
+        assert_eq!(None, parse_wait("Please wait 999999999999999999 minutes."));
+    }
+
+    #[test]
+    fn test_history_bounds() {
+        let dir = temp_dir("submit-history");
+        let mut history = History::load(dir.join("answers.txt")).unwrap();
+        let wrong = |outcome, answer: &str| Entry {
+            day: 7,
+            part: 2,
+            outcome,
+            answer: answer.to_string(),
+        };
+
+        history
+            .record(wrong(Outcome::Wrong(Some(Hint::TooHigh)), "100"))
+            .unwrap();
+        history
+            .record(wrong(Outcome::Wrong(Some(Hint::TooLow)), "40"))
+            .unwrap();
+        history.record(wrong(Outcome::Wrong(None), "66")).unwrap();
+
+        assert!(history.known(7, 2, "100").is_some());
+        assert!(history.known(7, 2, "250").is_some());
+        assert!(history.known(7, 2, "12").is_some());
+        assert!(history.known(7, 2, "66").is_some());
+        assert!(history.known(7, 2, "67").is_none());
+        assert!(history.known(7, 1, "250").is_none());
+
+        assert!(matches!(
+            history.record(wrong(Outcome::Wrong(None), "1\n7 2 right 5")),
+            Err(FetchError::BadAnswer(_))
+        ));
+        let reloaded = History::load(dir.join("answers.txt")).unwrap();
+        assert_eq!(3, reloaded.entries(7, 2).count());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_submit_never_resends() {
+        let (base_url, hits) = mock_server(|method, url, _, body| match (method, url) {
+            ("POST", "/2021/day/7/answer") if body == "level=2&answer=96592276" => (
+                200,
+                page("That's not the right answer; your answer is too high."),
+            ),
+            ("POST", "/2021/day/7/answer") if body == "level=2&answer=96592275" => {
+                (200, page("That's the right answer!"))
+            }
+            _ => (404, "Not Found".to_string()),
+        });
+        let dir = temp_dir("submit-mock");
+        let client = Client::new(&base_url, "abc", &dir);
+        let mut history = History::for_client(&client).unwrap();
+
+        assert_eq!(
+            Submission::Sent(Verdict::Wrong {
+                hint: Some(Hint::TooHigh),
+                wait: None
+            }),
+            submit(&client, &mut history, 7, 2, "96592276").unwrap()
+        );
+        assert!(matches!(
+            submit(&client, &mut history, 7, 2, "96592280").unwrap(),
+            Submission::Known(_)
+        ));
+        for answer in ["", "96592275 ", "1\n7 2 right 1"] {
+            assert!(matches!(
+                submit(&client, &mut history, 7, 2, answer),
+                Err(FetchError::BadAnswer(_))
+            ));
+        }
+        assert_eq!(1, hits.load(Ordering::SeqCst));
+
+        assert_eq!(
+            Submission::Sent(Verdict::Right),
+            submit(&client, &mut history, 7, 2, "96592275").unwrap()
+        );
+        assert!(matches!(
+            submit(&client, &mut history, 7, 2, "96592275").unwrap(),
+            Submission::Known(Entry {
+                outcome: Outcome::Right,
+                ..
+            })
+        ));
+        assert_eq!(2, hits.load(Ordering::SeqCst));
+
+        let history = History::for_client(&client).unwrap();
+        assert_eq!(2, history.entries(7, 2).count());
+
+        // Logging in again keeps the history.
+        let relogged = Client::new(&base_url, "def", &dir);
+        let history = History::for_client(&relogged).unwrap();
+        assert_eq!(2, history.entries(7, 2).count());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_rate_limit_is_not_recorded() {
+        let (base_url, _) = mock_server(|_, _, _, _| {
+            (
+                200,
+                page("You gave an answer too recently. You have 30s left to wait."),
+            )
+        });
+        let dir = temp_dir("submit-wait");
+        let client = Client::new(&base_url, "abc", &dir);
+        let mut history = History::for_client(&client).unwrap();
+
+        assert_eq!(
+            Submission::Sent(Verdict::TooSoon {
+                wait: Some(Duration::from_secs(30))
+            }),
+            submit(&client, &mut history, 1, 1, "1715").unwrap()
+        );
+        assert_eq!(0, history.entries(1, 1).count());
+    }
+}